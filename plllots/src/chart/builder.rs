@@ -1,36 +1,262 @@
-use super::ChartHelper;
-use crate::coordinate_system::CoordinateSystem;
-use crate::element::{Margins, Offsets, PlotSize};
-use crate::primitives::{AppendPrimitives, Primitives};
+use super::{ChartHelper, Facet, Grid, GridLayout, GridPosition, LinkedAxes, LinkedRanges};
+use crate::component::{Aria, AriaSeries, LabelInterval, LabelPosition, Marker};
+use crate::coordinate_system::{CoordinateSystem, RadarShape, SliceLabel, SliceLabelPosition};
+use crate::element::{Extents, Margins, Offsets, PlotSize, Symbol};
+use crate::primitives::{AppendPrimitives, Group, Primitives, grouped};
+use crate::series::{Series, data::Dataset};
+use crate::utils::text::TextMeasurer;
 use bon::Builder;
-use kurbo::{Cap, Stroke};
+use kurbo::{Cap, Point, Rect, Stroke};
+use parley::Alignment;
 use peniko::{Brush, Color};
+use std::borrow::Cow;
 
 #[derive(Debug, PartialEq, Clone, Builder)]
 pub struct Chart {
+    /// Coordinate systems with their own plot areas, drawn above the one of the chart.
+    #[builder(field)]
+    pub grids: Vec<Grid>,
     #[builder(with = |width: f64, height: f64| PlotSize { width, height })]
     pub size: PlotSize,
+    /// Kept around the plot area of the chart and, unless they set their own, of the grids.
     #[builder(default, setters(option_fn(vis = "")))]
     pub margins: Margins,
-    pub coordinate_system: CoordinateSystem,
+    /// Fills the whole chart inside of the margins.
+    #[builder(setters(option_fn(vis = "")))]
+    pub coordinate_system: Option<CoordinateSystem>,
+    /// Cells the grids are placed in, a single cell covering the whole chart by default.
+    #[builder(with = |rows: usize, columns: usize| GridLayout { rows, columns })]
+    pub layout: Option<GridLayout>,
+    /// Widens the value axes of all cartesian coordinate systems to one shared range.
+    #[builder(setters(option_fn(vis = "")))]
+    pub linked_axes: Option<LinkedAxes>,
+    /// Columns the series can refer to by name instead of owning their data.
+    #[builder(setters(option_fn(vis = "")))]
+    pub dataset: Option<Dataset>,
     #[builder(default = Theme::white(), setters(option_fn(vis = "")))]
     pub theme: Theme,
+    #[builder(setters(option_fn(vis = "")))]
+    pub aria: Option<Aria>,
+}
+
+impl<S: chart_builder::State> ChartBuilder<S> {
+    pub fn add_grid(mut self, grid: impl Into<Grid>) -> Self {
+        self.grids.push(grid.into());
+        self
+    }
+
+    pub fn set_grids(mut self, grids: impl IntoIterator<Item: Into<Grid>>) -> Self {
+        self.grids = grids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Adds the grids of `facet` in its layout, with the axes of all grids linked.
+    pub fn facet(
+        mut self,
+        facet: &Facet,
+    ) -> ChartBuilder<chart_builder::SetLinkedAxes<chart_builder::SetLayout<S>>>
+    where
+        S::Layout: chart_builder::IsUnset,
+        S::LinkedAxes: chart_builder::IsUnset,
+    {
+        self.grids.extend(facet.grids());
+        let layout = facet.layout();
+        self.layout(layout.rows, layout.columns)
+            .linked_axes(LinkedAxes::Both)
+    }
+}
+
+/// A coordinate system with the rectangle of the chart it is drawn in.
+struct Placement<'a> {
+    rect: Rect,
+    margins: &'a Margins,
+    coordinate_system: &'a CoordinateSystem,
+    title: Option<&'a str>,
 }
 
 impl Chart {
-    pub(crate) fn create_plot_helper(&self) -> ChartHelper {
-        ChartHelper {
-            plot_size: self.size.clone(),
-            margins: self.margins.clone(),
-            offsets: Offsets::from_margin(&self.size, &self.margins),
+    /// The series of all coordinate systems, the ones of the chart first.
+    pub fn series(&self) -> Vec<&Series> {
+        self.placements()
+            .flat_map(|placement| placement.coordinate_system.series())
+            .collect()
+    }
+
+    /// Dimensions that series read but the dataset of the chart lacks after its transforms,
+    /// each once. Series reading them are not drawn.
+    pub fn unknown_dimensions(&self) -> Vec<&str> {
+        let data = self.data();
+        let mut unknown = Vec::new();
+        for name in self.series().into_iter().flat_map(Series::dimensions) {
+            let known = data.as_deref().and_then(|data| data.get(name)).is_some();
+            if !known && !unknown.contains(&name) {
+                unknown.push(name);
+            }
         }
+        unknown
     }
 
-    pub(crate) fn generate_primitives(&self) -> Vec<Primitives> {
-        let mut helper = self.create_plot_helper();
-        let mut primitives = Vec::new();
+    /// Every series of the chart for assistive technology, including pies and radar series.
+    pub(crate) fn aria_series(&self) -> Vec<AriaSeries> {
+        let data = self.data();
+        let mut series = Vec::new();
+        for placement in self.placements() {
+            let index = series.len();
+            series.extend(
+                placement
+                    .coordinate_system
+                    .aria_series(index, data.as_deref()),
+            );
+        }
+        series
+    }
+
+    /// Every coordinate system where it is drawn, the one of the chart first.
+    fn placements(&self) -> impl Iterator<Item = Placement<'_>> {
+        let full = Rect::new(0.0, 0.0, self.size.width, self.size.height);
+        let layout = self.layout.clone().unwrap_or_default();
+        let mut next_cell = 0;
+        let grids = self.grids.iter().map(move |grid| {
+            let rect = match &grid.position {
+                Some(GridPosition::Rect(rect)) => *rect,
+                Some(GridPosition::Cell(row, column)) => layout.cell(&self.size, *row, *column),
+                None => {
+                    next_cell += 1;
+                    layout.nth_cell(&self.size, next_cell - 1)
+                }
+            };
+            Placement {
+                rect,
+                margins: grid.margins.as_ref().unwrap_or(&self.margins),
+                coordinate_system: &grid.coordinate_system,
+                title: grid.title.as_deref(),
+            }
+        });
         self.coordinate_system
-            .append_primitives(&mut primitives, &mut helper, &self.theme);
+            .iter()
+            .map(move |coordinate_system| Placement {
+                rect: full,
+                margins: &self.margins,
+                coordinate_system,
+                title: None,
+            })
+            .chain(grids)
+    }
+
+    /// The dataset after its transforms.
+    pub(crate) fn data(&self) -> Option<Cow<'_, Dataset>> {
+        self.dataset.as_ref().map(Dataset::transformed)
+    }
+
+    /// Raw ranges shared by the linked axes of all coordinate systems.
+    fn linked_ranges(&self, dataset: Option<&Dataset>) -> LinkedRanges {
+        let Some(linked_axes) = &self.linked_axes else {
+            return LinkedRanges::default();
+        };
+        let union = |a: Option<(f64, f64)>, b: Option<(f64, f64)>| match (a, b) {
+            (Some((min, max)), Some((b_min, b_max))) => Some((min.min(b_min), max.max(b_max))),
+            (a, b) => a.or(b),
+        };
+        let ranges = self
+            .placements()
+            .map(|placement| placement.coordinate_system.value_ranges(dataset))
+            .fold(LinkedRanges::default(), |ranges, other| LinkedRanges {
+                x: union(ranges.x, other.x),
+                y: union(ranges.y, other.y),
+            });
+        LinkedRanges {
+            x: ranges.x.filter(|_| *linked_axes != LinkedAxes::Y),
+            y: ranges.y.filter(|_| *linked_axes != LinkedAxes::X),
+        }
+    }
+
+    /// Positions of the plot areas of all coordinate systems, the one of the chart first, with
+    /// [`MarginType::Auto`](crate::element::MarginType::Auto) margins fitted to the axes.
+    pub(crate) fn plot_areas(&self, text: &TextMeasurer) -> Vec<Offsets> {
+        let data = self.data();
+        let linked = self.linked_ranges(data.as_deref());
+        self.placements()
+            .map(|placement| self.fit(&placement, text, &linked, data.as_deref()))
+            .collect()
+    }
+
+    fn fit(
+        &self,
+        placement: &Placement,
+        text: &TextMeasurer,
+        linked: &LinkedRanges,
+        dataset: Option<&Dataset>,
+    ) -> Offsets {
+        let (mut rect, margins) = (placement.rect, placement.margins);
+        // The title sits above the margins, whatever their type.
+        if placement.title.is_some() {
+            rect.y0 += self.theme.grid.title_font_size + self.theme.grid.title_margin;
+        }
+        let mut offsets = Offsets::within(rect, margins, &Extents::default());
+        if !margins.has_auto() {
+            return offsets;
+        }
+        // Category labels are laid out for the size of the plot area, so they are fitted
+        // once for a first estimate of it and once more for the resulting one.
+        for _ in 0..2 {
+            let extents =
+                placement
+                    .coordinate_system
+                    .extents(&self.theme, text, &offsets, linked, dataset);
+            offsets = Offsets::within(rect, margins, &extents);
+        }
+        offsets
+    }
+
+    pub(crate) fn generate_primitives(&self, text: &TextMeasurer) -> Vec<Primitives<'_>> {
+        let data = self.data();
+        let linked = self.linked_ranges(data.as_deref());
+        let mut primitives = Vec::new();
+        let grid_offset = usize::from(self.coordinate_system.is_some());
+        for (index, placement) in self.placements().enumerate() {
+            let grid = index.checked_sub(grid_offset);
+            let mut helper = ChartHelper {
+                plot_size: self.size.clone(),
+                margins: placement.margins.clone(),
+                offsets: self.fit(&placement, text, &linked, data.as_deref()),
+                text: text.clone(),
+                linked: linked.clone(),
+                dataset: data.as_deref(),
+                grid,
+            };
+            let Some(grid_index) = grid else {
+                placement.coordinate_system.append_primitives(
+                    &mut primitives,
+                    &mut helper,
+                    &self.theme,
+                );
+                continue;
+            };
+            let group = Group::new(format!("grid-{grid_index}"), "grid");
+            grouped(&mut primitives, group, |primitives| {
+                placement
+                    .coordinate_system
+                    .append_primitives(primitives, &mut helper, &self.theme);
+                if let Some(title) = placement.title {
+                    let theme = &self.theme.grid;
+                    grouped(primitives, Group::class("grid-title"), |primitives| {
+                        primitives.push(Primitives::Text(crate::primitives::Text {
+                            text: title.to_string(),
+                            fill_color: &theme.title_color,
+                            font_size: theme.title_font_size,
+                            text_anchor: Alignment::Middle,
+                            coord: Point::new(
+                                helper.offsets.plot_area().center().x,
+                                placement.rect.y0
+                                    + theme.title_margin
+                                    + theme.title_font_size / 2.0,
+                            ),
+                            rotation: None,
+                        }));
+                    });
+                }
+            });
+        }
         primitives
     }
 }
@@ -40,13 +266,21 @@ pub struct Theme {
     pub background: Color,
     pub cartesian_category_axis: CartesianAxisTheme,
     pub cartesian_value_axis: CartesianAxisTheme,
+    pub data_label: DataLabelTheme,
+    pub grid: GridTheme,
     pub line: LineTheme,
+    pub mark_area: MarkAreaTheme,
+    pub mark_line: MarkLineTheme,
+    pub mark_point: MarkPointTheme,
+    pub pie: PieTheme,
+    pub polar: PolarTheme,
+    pub radar: RadarTheme,
     pub scatter: ScatterTheme,
     pub series_colors: Vec<Brush>,
 }
 
 impl Theme {
-    fn white() -> Self {
+    pub(crate) fn white() -> Self {
         Self {
             background: Color::from_rgba8(0xff, 0xff, 0xff, 0xff),
             cartesian_category_axis: CartesianAxisTheme {
@@ -67,10 +301,24 @@ impl Theme {
                 split_lines_stroke: Stroke::new(1.0)
                     .with_start_cap(Cap::Square)
                     .with_end_cap(Cap::Square),
+                minor_ticks_show: false,
+                minor_ticks_length: 3.0,
+                minor_ticks_stroke: Stroke::new(1.0)
+                    .with_start_cap(Cap::Square)
+                    .with_end_cap(Cap::Square),
+                minor_ticks_color: Brush::Solid(Color::from_rgba8(0x6e, 0x70, 0x79, 0xff)),
+                minor_split_lines_show: false,
+                minor_split_lines_color: Brush::Solid(Color::from_rgba8(0xf4, 0xf7, 0xfd, 0xff)),
+                minor_split_lines_stroke: Stroke::new(1.0)
+                    .with_start_cap(Cap::Square)
+                    .with_end_cap(Cap::Square),
+                minor_split_number: 5,
                 labels_show: true,
                 labels_margin: 8.0,
                 labels_color: Brush::Solid(Color::from_rgba8(0x6e, 0x70, 0x79, 0xff)),
                 labels_font_size: 12.0,
+                labels_interval: LabelInterval::Auto,
+                labels_auto_rotate: true,
             },
             cartesian_value_axis: CartesianAxisTheme {
                 axis_show: false,
@@ -90,21 +338,112 @@ impl Theme {
                 split_lines_stroke: Stroke::new(1.0)
                     .with_start_cap(Cap::Square)
                     .with_end_cap(Cap::Square),
+                minor_ticks_show: false,
+                minor_ticks_length: 3.0,
+                minor_ticks_stroke: Stroke::new(1.0)
+                    .with_start_cap(Cap::Square)
+                    .with_end_cap(Cap::Square),
+                minor_ticks_color: Brush::Solid(Color::from_rgba8(0x6e, 0x70, 0x79, 0xff)),
+                minor_split_lines_show: false,
+                minor_split_lines_color: Brush::Solid(Color::from_rgba8(0xf4, 0xf7, 0xfd, 0xff)),
+                minor_split_lines_stroke: Stroke::new(1.0)
+                    .with_start_cap(Cap::Square)
+                    .with_end_cap(Cap::Square),
+                minor_split_number: 5,
                 labels_show: true,
                 labels_margin: 8.0,
                 labels_color: Brush::Solid(Color::from_rgba8(0x6e, 0x70, 0x79, 0xff)),
                 labels_font_size: 12.0,
+                labels_interval: LabelInterval::Auto,
+                labels_auto_rotate: false,
+            },
+            data_label: DataLabelTheme {
+                position: LabelPosition::Top,
+                distance: 5.0,
+                color: Brush::Solid(Color::from_rgba8(0x33, 0x33, 0x33, 0xff)),
+                font_size: 12.0,
+                hide_overlap: false,
+            },
+            grid: GridTheme {
+                title_color: Brush::Solid(Color::from_rgba8(0x33, 0x33, 0x33, 0xff)),
+                title_font_size: 14.0,
+                title_margin: 8.0,
             },
             line: LineTheme {
                 stroke: Stroke::new(2.0),
                 symbol_show: true,
+                symbol: Symbol::Circle,
                 symbol_stroke: Stroke::new(2.0),
                 symbol_fill_color: Brush::Solid(Color::from_rgba8(0xff, 0xff, 0xff, 0xff)),
                 symbol_size: 2.0,
             },
+            mark_area: MarkAreaTheme {
+                fill_color: Brush::Solid(Color::from_rgba8(0xb0, 0xc4, 0xde, 0x4d)),
+                border_stroke: Stroke::new(0.0),
+                border_color: Brush::Solid(Color::from_rgba8(0x6e, 0x70, 0x79, 0xff)),
+                label_margin: 5.0,
+                label_color: Brush::Solid(Color::from_rgba8(0x6e, 0x70, 0x79, 0xff)),
+                label_font_size: 12.0,
+            },
+            mark_line: MarkLineTheme {
+                stroke: Stroke::new(1.0).with_dashes(0.0, [5.0, 5.0]),
+                color: Brush::Solid(Color::from_rgba8(0x6e, 0x70, 0x79, 0xff)),
+                label_show: true,
+                label_margin: 5.0,
+                label_font_size: 12.0,
+            },
+            mark_point: MarkPointTheme {
+                marker: Marker::Pin,
+                marker_size: 16.0,
+                marker_stroke: Stroke::new(1.5),
+                color: Brush::Solid(Color::from_rgba8(0x6e, 0x70, 0x79, 0xff)),
+                label_color: Brush::Solid(Color::from_rgba8(0x33, 0x33, 0x33, 0xff)),
+                label_font_size: 12.0,
+                label_background: Brush::Solid(Color::from_rgba8(0xff, 0xff, 0xff, 0xe6)),
+                label_border_stroke: Stroke::new(1.0),
+                label_border_color: Brush::Solid(Color::from_rgba8(0xcc, 0xcc, 0xcc, 0xff)),
+                label_padding: 4.0,
+            },
+            pie: PieTheme {
+                inner_radius: 0.0,
+                outer_radius: 75.0,
+                start_angle: 90.0,
+                pad_angle: 0.0,
+                border_stroke: Stroke::new(1.0),
+                border_color: Brush::Solid(Color::from_rgba8(0xff, 0xff, 0xff, 0xff)),
+                label_show: true,
+                label: SliceLabel::Name,
+                label_position: SliceLabelPosition::Outside,
+                label_color: Brush::Solid(Color::from_rgba8(0x33, 0x33, 0x33, 0xff)),
+                label_font_size: 12.0,
+                label_gap: 4.0,
+                leader_length: 12.0,
+                leader_stroke: Stroke::new(1.0),
+            },
+            polar: PolarTheme {
+                radius: 75.0,
+                start_angle: 90.0,
+            },
+            radar: RadarTheme {
+                radius: 75.0,
+                start_angle: 90.0,
+                shape: RadarShape::Polygon,
+                split_number: 5,
+                axis_stroke: Stroke::new(1.0),
+                axis_color: Brush::Solid(Color::from_rgba8(0xcc, 0xcc, 0xcc, 0xff)),
+                split_lines_stroke: Stroke::new(1.0),
+                split_lines_color: Brush::Solid(Color::from_rgba8(0xe0, 0xe6, 0xe1, 0xff)),
+                labels_show: true,
+                labels_margin: 8.0,
+                labels_color: Brush::Solid(Color::from_rgba8(0x6e, 0x70, 0x79, 0xff)),
+                labels_font_size: 12.0,
+                area_show: false,
+                area_opacity: 0.3,
+            },
             scatter: ScatterTheme {
                 stroke: Stroke::new(0.0),
                 stroke_color: Brush::Solid(Color::from_rgba8(0xee, 0xee, 0xee, 0xff)),
+                symbol: Symbol::Circle,
                 symbol_size: 10.0,
             },
             series_colors: vec![
@@ -138,24 +477,310 @@ pub struct CartesianAxisTheme {
     pub split_lines_show: bool,
     pub split_lines_color: Brush,
     pub split_lines_stroke: Stroke,
+    // Minor ticks and split lines are only drawn by value axes.
+    pub minor_ticks_show: bool,
+    pub minor_ticks_length: f64,
+    pub minor_ticks_stroke: Stroke,
+    pub minor_ticks_color: Brush,
+    pub minor_split_lines_show: bool,
+    pub minor_split_lines_color: Brush,
+    pub minor_split_lines_stroke: Stroke,
+    /// Number of parts each interval between major ticks is split into.
+    pub minor_split_number: usize,
     pub labels_show: bool,
     pub labels_margin: f64,
     pub labels_color: Brush,
     pub labels_font_size: f64,
+    // The label interval and auto rotation are only used by category axes.
+    pub labels_interval: LabelInterval,
+    pub labels_auto_rotate: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataLabelTheme {
+    pub position: LabelPosition,
+    pub distance: f64,
+    pub color: Brush,
+    pub font_size: f64,
+    pub hide_overlap: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridTheme {
+    pub title_color: Brush,
+    pub title_font_size: f64,
+    /// Space between the top of a grid and its title.
+    pub title_margin: f64,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct LineTheme {
     pub stroke: Stroke,
     pub symbol_show: bool,
+    pub symbol: Symbol,
     pub symbol_stroke: Stroke,
     pub symbol_fill_color: Brush,
     pub symbol_size: f64,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkAreaTheme {
+    pub fill_color: Brush,
+    pub border_stroke: Stroke,
+    pub border_color: Brush,
+    pub label_margin: f64,
+    pub label_color: Brush,
+    pub label_font_size: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkLineTheme {
+    pub stroke: Stroke,
+    /// Color of lines on the cartesian, lines of a series use the color of the series.
+    pub color: Brush,
+    pub label_show: bool,
+    pub label_margin: f64,
+    pub label_font_size: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkPointTheme {
+    pub marker: Marker,
+    pub marker_size: f64,
+    pub marker_stroke: Stroke,
+    /// Color of points on the cartesian, points of a series use the color of the series.
+    pub color: Brush,
+    pub label_color: Brush,
+    pub label_font_size: f64,
+    pub label_background: Brush,
+    pub label_border_stroke: Stroke,
+    pub label_border_color: Brush,
+    pub label_padding: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PieTheme {
+    /// Radii in percent of half the shorter side of the plot area.
+    pub inner_radius: f64,
+    pub outer_radius: f64,
+    /// In degrees, counterclockwise from three o'clock.
+    pub start_angle: f64,
+    pub pad_angle: f64,
+    pub border_stroke: Stroke,
+    pub border_color: Brush,
+    pub label_show: bool,
+    pub label: SliceLabel,
+    pub label_position: SliceLabelPosition,
+    pub label_color: Brush,
+    pub label_font_size: f64,
+    /// Space between the end of a leader line and its label.
+    pub label_gap: f64,
+    pub leader_length: f64,
+    pub leader_stroke: Stroke,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolarTheme {
+    /// In percent of half the shorter side of the plot area.
+    pub radius: f64,
+    /// In degrees, counterclockwise from three o'clock.
+    pub start_angle: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadarTheme {
+    /// In percent of half the shorter side of the plot area.
+    pub radius: f64,
+    /// In degrees, counterclockwise from three o'clock.
+    pub start_angle: f64,
+    pub shape: RadarShape,
+    pub split_number: usize,
+    pub axis_stroke: Stroke,
+    pub axis_color: Brush,
+    pub split_lines_stroke: Stroke,
+    pub split_lines_color: Brush,
+    pub labels_show: bool,
+    pub labels_margin: f64,
+    pub labels_color: Brush,
+    pub labels_font_size: f64,
+    pub area_show: bool,
+    pub area_opacity: f32,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ScatterTheme {
     pub stroke: Stroke,
     pub stroke_color: Brush,
+    pub symbol: Symbol,
     pub symbol_size: f64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{CategoryAxis, ValueAxis};
+    use crate::coordinate_system::Cartesian;
+    use crate::series::Line;
+
+    fn chart(values: Vec<f64>) -> Chart {
+        Chart::builder()
+            .size(400.0, 300.0)
+            .margins(Margins::auto())
+            .coordinate_system(CoordinateSystem::Cartesian(
+                Cartesian::builder()
+                    .x_axis(
+                        CategoryAxis::builder()
+                            .data(bon::vec!["Mon", "Tue", "Wed"])
+                            .build(),
+                    )
+                    .y_axis(ValueAxis::default())
+                    .add_series(Line::builder().data(values).build())
+                    .build(),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn auto_margins() {
+        let text = TextMeasurer::new();
+        let small = chart(vec![150.0, 230.0, 224.0]).plot_areas(&text).remove(0);
+        let large = chart(vec![150_000.0, 230_000.0, 224_000.0])
+            .plot_areas(&text)
+            .remove(0);
+
+        let label_width = text.measure("250000", 12.0).width;
+        assert!(large.x_axis_start > label_width + 8.0);
+        assert!(large.x_axis_start > small.x_axis_start);
+        assert!(small.y_axis_start < 300.0 - 5.0 - 8.0 - 12.0);
+        assert_eq!(small.y_axis_start, large.y_axis_start);
+    }
+
+    #[test]
+    fn grids() {
+        let text = TextMeasurer::new();
+        let cartesian = |values: Vec<f64>| chart(values).coordinate_system.unwrap();
+        let dashboard = Chart::builder()
+            .size(400.0, 300.0)
+            .margins(Margins::auto())
+            .layout(2, 2)
+            .linked_axes(LinkedAxes::Y)
+            .add_grid(cartesian(vec![1.0, 2.0, 3.0]))
+            .add_grid(cartesian(vec![10.0, 20.0, 95.0]))
+            .add_grid(
+                Grid::builder()
+                    .position(Rect::new(0.0, 150.0, 400.0, 300.0))
+                    .coordinate_system(cartesian(vec![4.0, 5.0, 6.0]))
+                    .build(),
+            )
+            .build();
+
+        let areas = dashboard.plot_areas(&text);
+        assert_eq!(areas.len(), 3);
+        // The first two grids fill the top row, the third spans the bottom of the chart.
+        assert!(areas[0].x_axis_end < 200.0 && areas[1].x_axis_start > 200.0);
+        assert!(areas[1].y_axis_start < 150.0 && areas[2].y_axis_end > 150.0);
+        assert!(areas[2].x_axis_end > 300.0);
+
+        assert_eq!(
+            dashboard.linked_ranges(None),
+            LinkedRanges {
+                x: None,
+                y: Some((1.0, 95.0)),
+            }
+        );
+        // Linked axes need room for the same labels in every grid.
+        assert_eq!(areas[0].x_axis_start, areas[1].x_axis_start - 200.0);
+
+        let ids: Vec<String> = dashboard
+            .generate_primitives(&text)
+            .into_iter()
+            .filter_map(|primitive| match primitive {
+                Primitives::PushGroup(group) => group.id,
+                _ => None,
+            })
+            .collect();
+        assert!(ids.contains(&"grid-1-series-0".to_string()));
+        assert!(ids.contains(&"grid-2-y-axis-0".to_string()));
+        assert_eq!(
+            ids.len(),
+            ids.iter().collect::<std::collections::HashSet<_>>().len()
+        );
+    }
+
+    #[test]
+    fn grid_title() {
+        let text = TextMeasurer::new();
+        let grid = |title: Option<&str>| Grid {
+            title: title.map(String::from),
+            ..Grid::builder()
+                .position(Rect::new(0.0, 100.0, 400.0, 300.0))
+                .margins(Margins::default())
+                .coordinate_system(chart(vec![1.0, 2.0]).coordinate_system.unwrap())
+                .build()
+        };
+        let chart = Chart::builder()
+            .size(400.0, 300.0)
+            .add_grid(grid(None))
+            .add_grid(grid(Some("Latency")))
+            .build();
+
+        let areas = chart.plot_areas(&text);
+        let theme = &chart.theme.grid;
+        // The pixel margin is kept below the title.
+        assert_eq!(areas[0].y_axis_end, 160.0);
+        assert_eq!(
+            areas[1].y_axis_end,
+            160.0 + theme.title_font_size + theme.title_margin
+        );
+    }
+
+    #[test]
+    fn circular_auto_margins() {
+        use crate::coordinate_system::{Pie, Radar};
+        use crate::series::RadarSeries;
+
+        let text = TextMeasurer::new();
+        let pie = Pie::builder()
+            .add_slice(("Hosting", 1.0))
+            .add_slice(("Salaries", 6.0))
+            .add_slice(("Office", 1.0))
+            .label(SliceLabel::NameAndPercent)
+            .label_position(SliceLabelPosition::Outside)
+            .outer_radius(100.0)
+            .build();
+        let radar = Radar::builder()
+            .set_indicators([("Availability", 100.0), ("Latency", 100.0)])
+            .add_series(RadarSeries::builder().add_row(vec![99.0, 20.0]).build())
+            .radius(100.0)
+            .build();
+
+        for coordinate_system in [
+            CoordinateSystem::Pie(Box::new(pie)),
+            CoordinateSystem::Radar(Box::new(radar)),
+        ] {
+            let chart = Chart::builder()
+                .size(400.0, 300.0)
+                .margins(Margins::auto())
+                .coordinate_system(coordinate_system)
+                .build();
+            // Every label fits into the chart.
+            for primitive in chart.generate_primitives(&text) {
+                let Primitives::Text(label) = primitive else {
+                    continue;
+                };
+                let size = text.measure(&label.text, label.font_size);
+                let (left, right) = match label.text_anchor {
+                    Alignment::Start => (label.coord.x, label.coord.x + size.width),
+                    Alignment::End => (label.coord.x - size.width, label.coord.x),
+                    _ => (
+                        label.coord.x - size.width / 2.0,
+                        label.coord.x + size.width / 2.0,
+                    ),
+                };
+                assert!(left >= 0.0 && right <= 400.0, "{} overflows", label.text);
+                assert!(label.coord.y - size.height / 2.0 >= 0.0);
+                assert!(label.coord.y + size.height / 2.0 <= 300.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use bon::Builder;
+
+use crate::{
+    chart::{Grid, GridLayout},
+    component::{CategoryAxis, ValueAxis},
+    coordinate_system::{Cartesian, CoordinateSystem},
+    series::{
+        Line, LineData, Scatter, ScatterData, Series,
+        data::{Dataset, Numbers, PlotData},
+    },
+};
+
+/// Kind of the series drawn in every facet.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FacetSeries {
+    Line,
+    Scatter,
+}
+
+/// Small multiples from grouped rows: one [`Cartesian`] per distinct group, in the order the
+/// groups first appear, each titled with its group.
+///
+/// Pass it to [`ChartBuilder::facet`](super::ChartBuilder::facet), which lays the grids out
+/// and links their axes so all facets share one range.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct Facet {
+    /// The group of every row.
+    #[builder(with = |groups: impl IntoIterator<Item: Into<String>>| groups.into_iter().map(Into::into).collect())]
+    pub groups: Vec<String>,
+    /// Categories or values along the x axis, one per row.
+    #[builder(into)]
+    pub x: PlotData,
+    #[builder(into)]
+    pub y: Numbers,
+    /// Splits every facet into one series per distinct value, so the same value has the same
+    /// color in all facets.
+    #[builder(with = |colors: impl IntoIterator<Item: Into<String>>| colors.into_iter().map(Into::into).collect())]
+    pub color_by: Option<Vec<String>>,
+    #[builder(default = FacetSeries::Line, setters(option_fn(vis = "")))]
+    pub series: FacetSeries,
+    /// Number of columns of the layout, defaults to the smallest square fitting all facets.
+    #[builder(setters(option_fn(vis = "")))]
+    pub columns: Option<usize>,
+}
+
+/// Distinct values in the order they first appear.
+fn distinct(values: &[String]) -> Vec<&str> {
+    let mut distinct: Vec<&str> = Vec::new();
+    for value in values {
+        if !distinct.contains(&value.as_str()) {
+            distinct.push(value);
+        }
+    }
+    distinct
+}
+
+impl Facet {
+    /// A facet of the dimensions of `dataset` after its transforms, grouped by the dimension
+    /// `group_by`. `None` if a dimension is missing or `y` is not numeric.
+    pub fn from_dataset(dataset: &Dataset, group_by: &str, x: &str, y: &str) -> Option<Facet> {
+        let dataset = dataset.transformed();
+        Some(Facet {
+            groups: dataset.get(group_by)?.to_strings(),
+            x: dataset.get(x)?.clone(),
+            y: dataset.get(y)?.as_numbers()?.clone(),
+            color_by: None,
+            series: FacetSeries::Line,
+            columns: None,
+        })
+    }
+
+    pub fn layout(&self) -> GridLayout {
+        let count = distinct(&self.groups).len().max(1);
+        let columns = self
+            .columns
+            .unwrap_or_else(|| (count as f64).sqrt().ceil() as usize)
+            .max(1);
+        GridLayout {
+            rows: count.div_ceil(columns),
+            columns,
+        }
+    }
+
+    /// One titled grid per group.
+    pub fn grids(&self) -> Vec<Grid> {
+        let colors = self.color_by.as_deref().map(distinct);
+        let categories = match &self.x {
+            PlotData::String(categories) => Some(distinct(categories)),
+            _ => None,
+        };
+
+        distinct(&self.groups)
+            .into_iter()
+            .map(|group| {
+                let rows: Vec<usize> = (0..self.groups.len())
+                    .filter(|&row| self.groups[row] == group)
+                    .collect();
+                // Every facet gets every series, even empty ones, so the series and with them
+                // their colors have the same positions in all facets.
+                let series = match (&self.color_by, &colors) {
+                    (Some(color_by), Some(colors)) => colors
+                        .iter()
+                        .map(|color| {
+                            let rows: Vec<usize> = rows
+                                .iter()
+                                .copied()
+                                .filter(|&row| color_by[row] == *color)
+                                .collect();
+                            self.series(Some(color), &rows, categories.as_deref())
+                        })
+                        .collect(),
+                    _ => vec![self.series(None, &rows, categories.as_deref())],
+                };
+                let cartesian = Cartesian::builder()
+                    .set_series(series)
+                    .y_axis(ValueAxis::default());
+                let cartesian = match &categories {
+                    Some(categories) => cartesian
+                        .x_axis(
+                            CategoryAxis::builder()
+                                .data(categories.iter().map(|c| c.to_string()).collect())
+                                .build(),
+                        )
+                        .build(),
+                    None => cartesian.x_axis(ValueAxis::default()).build(),
+                };
+                Grid::builder()
+                    .title(group)
+                    .coordinate_system(CoordinateSystem::Cartesian(cartesian))
+                    .build()
+            })
+            .collect()
+    }
+
+    /// The series of `rows`, with the values on the index of their category if there are any.
+    fn series(&self, name: Option<&str>, rows: &[usize], categories: Option<&[&str]>) -> Series {
+        let y = |row: usize| self.y.get(row).unwrap_or(f64::NAN);
+        let columns = match (&self.x, categories) {
+            (PlotData::String(x), Some(categories)) => {
+                let mut values = vec![f64::NAN; categories.len()];
+                for &row in rows {
+                    if let Some(index) = categories.iter().position(|c| *c == x[row]) {
+                        values[index] = y(row);
+                    }
+                }
+                vec![values]
+            }
+            (_, _) if rows.is_empty() => vec![vec![f64::NAN], vec![f64::NAN]],
+            (x, _) => vec![
+                rows.iter().map(|&row| y(row)).collect(),
+                rows.iter()
+                    .map(|&row| x.get_float(row).unwrap_or(f64::NAN))
+                    .collect(),
+            ],
+        };
+        let name = name.map(str::to_string);
+        match self.series {
+            FacetSeries::Line => {
+                let mut line = Line::builder().data(LineData::from(columns)).build();
+                line.name = name;
+                line.into()
+            }
+            FacetSeries::Scatter => {
+                let data = ScatterData::builder().data(columns).build();
+                let mut scatter = Scatter::builder().data(data).build();
+                scatter.name = name;
+                scatter.into()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{chart::Chart, renderer::SvgRenderer};
+
+    #[test]
+    fn small_multiples() {
+        let facet = Facet::builder()
+            .groups(["eu", "eu", "us", "us", "apac"])
+            .x(PlotData::String(bon::vec![
+                "Mon", "Tue", "Mon", "Tue", "Tue"
+            ]))
+            .y(vec![1.0, 2.0, 3.0, 4.0, 5.0])
+            .color_by(["api", "db", "api", "api", "db"])
+            .build();
+        assert_eq!(
+            facet.layout(),
+            GridLayout {
+                rows: 2,
+                columns: 2
+            }
+        );
+
+        let grids = facet.grids();
+        assert_eq!(grids.len(), 3);
+        assert_eq!(grids[1].title.as_deref(), Some("us"));
+        // Every facet has a series per color, in the same order, empty ones included.
+        let CoordinateSystem::Cartesian(apac) = &grids[2].coordinate_system else {
+            panic!("facets are cartesian");
+        };
+        let names: Vec<_> = apac.series.iter().map(|s| s.name()).collect();
+        assert_eq!(names, [Some("api"), Some("db")]);
+        let Series::Line(db) = &apac.series[1] else {
+            panic!("facets draw lines by default");
+        };
+        assert!(db.data.data[0].get(0).unwrap().is_nan());
+        assert_eq!(db.data.data[0].get(1), Some(5.0));
+
+        let chart = Chart::builder().size(600.0, 400.0).facet(&facet).build();
+        let output = SvgRenderer::new().render_to_string(&chart);
+        assert!(output.contains(r#"<g class="grid" id="grid-2">"#));
+        assert!(output.contains("\napac\n</text>"));
+    }
+
+    #[test]
+    fn from_dataset() {
+        use crate::series::transform::{SortOrder, Transform};
+
+        let dataset = Dataset::builder()
+            .add_dimension("region", vec!["eu".to_string(), "us".into(), "eu".into()])
+            .add_dimension("day", vec![1_i64, 1, 2])
+            .add_dimension("latency", vec![10_u64, 20, 30])
+            .add_transform(Transform::Sort {
+                dimension: "latency".into(),
+                order: SortOrder::Descending,
+            })
+            .build();
+        let facet = Facet::from_dataset(&dataset, "region", "day", "latency").unwrap();
+        assert_eq!(facet.groups, ["eu", "us", "eu"]);
+        assert_eq!(facet.y, Numbers::from(vec![30_u64, 20, 10]));
+        assert_eq!(facet.grids().len(), 2);
+
+        assert_eq!(
+            Facet::from_dataset(&dataset, "region", "day", "region"),
+            None
+        );
+        assert_eq!(
+            Facet::from_dataset(&dataset, "host", "day", "latency"),
+            None
+        );
+    }
+}
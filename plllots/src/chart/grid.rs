@@ -0,0 +1,99 @@
+use bon::Builder;
+use kurbo::Rect;
+
+use crate::{
+    coordinate_system::CoordinateSystem,
+    element::{Margins, PlotSize},
+};
+
+/// Where a [`Grid`] is placed in its chart.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GridPosition {
+    /// Row and column of a cell of the [`GridLayout`] of the chart.
+    Cell(usize, usize),
+    /// A rectangle of the chart in pixels.
+    Rect(Rect),
+}
+
+impl From<(usize, usize)> for GridPosition {
+    fn from((row, column): (usize, usize)) -> Self {
+        Self::Cell(row, column)
+    }
+}
+
+impl From<Rect> for GridPosition {
+    fn from(value: Rect) -> Self {
+        Self::Rect(value)
+    }
+}
+
+/// Splits a chart into `rows` × `columns` cells of the same size.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridLayout {
+    pub rows: usize,
+    pub columns: usize,
+}
+
+impl Default for GridLayout {
+    fn default() -> Self {
+        Self {
+            rows: 1,
+            columns: 1,
+        }
+    }
+}
+
+impl GridLayout {
+    /// Rectangle of the cell at `row` and `column` of a chart of `size`.
+    pub(crate) fn cell(&self, size: &PlotSize, row: usize, column: usize) -> Rect {
+        let width = size.width / self.columns.max(1) as f64;
+        let height = size.height / self.rows.max(1) as f64;
+        Rect::new(
+            column as f64 * width,
+            row as f64 * height,
+            (column + 1) as f64 * width,
+            (row + 1) as f64 * height,
+        )
+    }
+
+    /// Rectangle of the `index`th cell, counting along the rows from the top left.
+    pub(crate) fn nth_cell(&self, size: &PlotSize, index: usize) -> Rect {
+        let columns = self.columns.max(1);
+        self.cell(size, index / columns, index % columns)
+    }
+}
+
+/// A coordinate system with its own plot area, e.g. one panel of a dashboard of small
+/// multiples.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct Grid {
+    /// Defaults to the next cell of the layout that no other grid without a position took.
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub position: Option<GridPosition>,
+    /// Kept inside of the rectangle of the grid, defaults to the margins of the chart.
+    #[builder(setters(option_fn(vis = "")))]
+    pub margins: Option<Margins>,
+    /// Drawn centered above the plot area.
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub title: Option<String>,
+    pub coordinate_system: CoordinateSystem,
+}
+
+impl From<CoordinateSystem> for Grid {
+    fn from(coordinate_system: CoordinateSystem) -> Self {
+        Self {
+            position: None,
+            margins: None,
+            title: None,
+            coordinate_system,
+        }
+    }
+}
+
+/// Axes of the cartesian coordinate systems of a chart that show one shared range.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinkedAxes {
+    X,
+    Y,
+    Both,
+}
@@ -1,11 +1,68 @@
 pub mod builder;
+pub mod facet;
+pub mod grid;
 
+use crate::component::AxisType;
 use crate::element::{Margins, Offsets, PlotSize};
+use crate::series::data::Dataset;
+use crate::utils::text::TextMeasurer;
 pub use builder::*;
+pub use facet::*;
+pub use grid::*;
 
 #[derive(Debug, Clone)]
-pub struct ChartHelper {
+pub struct ChartHelper<'a> {
     pub plot_size: PlotSize,
     pub margins: Margins,
     pub offsets: Offsets,
+    pub text: TextMeasurer,
+    pub linked: LinkedRanges,
+    pub dataset: Option<&'a Dataset>,
+    /// Index of the grid being drawn, `None` for the coordinate system of the chart itself.
+    pub grid: Option<usize>,
+}
+
+/// Raw data ranges that value axes widen their scale to, so linked axes of several grids
+/// show the same range.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LinkedRanges {
+    pub x: Option<(f64, f64)>,
+    pub y: Option<(f64, f64)>,
+}
+
+#[cfg(test)]
+impl ChartHelper<'static> {
+    /// A helper for a chart of `size` with the default margins and no dataset.
+    pub(crate) fn with_size(size: PlotSize) -> Self {
+        let margins = Margins::default();
+        ChartHelper {
+            offsets: Offsets::from_margin(&size, &margins),
+            plot_size: size,
+            margins,
+            text: TextMeasurer::new(),
+            linked: LinkedRanges::default(),
+            dataset: None,
+            grid: None,
+        }
+    }
+}
+
+impl ChartHelper<'_> {
+    /// Id of the element `name`, prefixed with the id of the grid so ids are unique across
+    /// grids.
+    pub(crate) fn id(&self, name: &str) -> String {
+        match self.grid {
+            Some(grid) => format!("grid-{grid}-{name}"),
+            None => name.to_string(),
+        }
+    }
+}
+
+impl LinkedRanges {
+    pub(crate) fn get(&self, axis_type: &AxisType) -> Option<(f64, f64)> {
+        match axis_type {
+            AxisType::XAxis => self.x,
+            AxisType::YAxis => self.y,
+        }
+    }
 }
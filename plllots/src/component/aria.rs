@@ -0,0 +1,30 @@
+use bon::Builder;
+
+/// Accessibility options for the rendered chart.
+///
+/// When set on a chart, the SVG output is exposed to assistive technology as a single image
+/// with a title, a description and an optional data table hidden from sighted users.
+#[derive(Debug, Builder, Clone, Default, PartialEq)]
+pub struct Aria {
+    /// Accessible name of the chart, used for `<title>` and `aria-label`.
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub label: Option<String>,
+    /// Free text put in front of the generated description in `<desc>`.
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub description: Option<String>,
+    /// Appends a sentence per series with its name, point count and value range.
+    #[builder(default = true)]
+    pub series_summary: bool,
+    /// Adds an invisible table per series holding the raw data.
+    #[builder(default)]
+    pub data_table: bool,
+}
+
+/// What assistive technology learns about one series, a pie or a radar series: a sentence
+/// summarizing it and its data as labelled columns of formatted values.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct AriaSeries {
+    pub name: String,
+    pub summary: String,
+    pub columns: Vec<(String, Vec<String>)>,
+}
@@ -1,17 +1,25 @@
 use std::f64;
 
 use bon::Builder;
-use kurbo::{Point, Stroke};
+use kurbo::{Point, Size, Stroke};
 use parley::Alignment;
 use peniko::Brush;
 
 use crate::{
     chart::{ChartHelper, Theme},
-    primitives::Primitives,
-    series::{Series, data::PlotData},
-    utils::{get_raw_range, get_scale_details},
+    element::{Extents, Offsets},
+    primitives::{Group, Primitives, grouped},
+    series::{Series, data::Dataset},
+    utils::{
+        get_minor_values, get_scale_details,
+        text::TextMeasurer,
+        time::{format_time, get_time_scale_details},
+    },
 };
 
+/// Most major ticks a value axis draws with a fixed interval.
+const MAX_TICKS: f64 = 1000.0;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum CartesianAxis {
     Category(Vec<CategoryAxis>),
@@ -47,6 +55,45 @@ pub(crate) enum SingleCartesianAxis<'a> {
     Value((&'a ValueAxis, &'a ValueAxisMeta)),
 }
 
+impl SingleCartesianAxis<'_> {
+    /// Pixel position of `value`, which is the index of the category on category axes.
+    pub(crate) fn position(&self, axis_type: &AxisType, helper: &ChartHelper, value: f64) -> f64 {
+        match self {
+            SingleCartesianAxis::Category(axis) => {
+                axis.pos_closure(axis_type, helper)(Some(value.round().max(0.0) as usize), None)
+            }
+            SingleCartesianAxis::Value((axis, meta)) => {
+                axis.pos_closure(axis_type, meta, helper)(None, Some(value))
+            }
+        }
+    }
+
+    /// Ordered pixel range from `from` to `to`, covering the whole band of the categories on
+    /// category axes.
+    pub(crate) fn range(
+        &self,
+        axis_type: &AxisType,
+        helper: &ChartHelper,
+        from: f64,
+        to: f64,
+    ) -> (f64, f64) {
+        let (from, to) = (
+            self.position(axis_type, helper, from),
+            self.position(axis_type, helper, to),
+        );
+        let half_band = match (self, axis_type) {
+            (SingleCartesianAxis::Category(axis), AxisType::XAxis) => {
+                helper.offsets.x_span / axis.data.len() as f64 / 2.0
+            }
+            (SingleCartesianAxis::Category(axis), AxisType::YAxis) => {
+                helper.offsets.y_span / axis.data.len() as f64 / 2.0
+            }
+            (SingleCartesianAxis::Value(_), _) => 0.0,
+        };
+        (from.min(to) - half_band, from.max(to) + half_band)
+    }
+}
+
 impl<'a> From<&'a CategoryAxis> for SingleCartesianAxis<'a> {
     fn from(value: &'a CategoryAxis) -> Self {
         SingleCartesianAxis::Category(value)
@@ -99,11 +146,31 @@ pub struct CategoryAxis {
     pub labels_alignment: Option<Alignment>,
     #[builder(setters(option_fn(vis = "")))]
     pub labels_rotation: Option<f64>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub labels_interval: Option<LabelInterval>,
+    /// Rotates the labels of an x axis when they would overlap and no rotation is set.
+    #[builder(setters(option_fn(vis = "")))]
+    pub labels_auto_rotate: Option<bool>,
+    /// Labels wider than this are truncated with an ellipsis.
+    #[builder(setters(option_fn(vis = "")))]
+    pub labels_max_width: Option<f64>,
     pub data: Vec<String>,
 }
 
 #[derive(Debug, Builder, Clone, Default, PartialEq)]
 pub struct ValueAxis {
+    /// Fixed bounds instead of the rounded range of the data.
+    #[builder(setters(option_fn(vis = "")))]
+    pub min: Option<f64>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub max: Option<f64>,
+    /// Distance between major ticks instead of a rounded sixth of the range.
+    #[builder(setters(option_fn(vis = "")))]
+    pub interval: Option<f64>,
+    /// Reads the values as seconds since the Unix epoch: major ticks fall on whole seconds,
+    /// minutes, hours or days and the labels show UTC dates and times.
+    #[builder(default, setters(option_fn(vis = "")))]
+    pub time: bool,
     #[builder(setters(option_fn(vis = "")))]
     pub axis_show: Option<bool>,
     #[builder(setters(option_fn(vis = "")))]
@@ -131,6 +198,23 @@ pub struct ValueAxis {
     #[builder(setters(option_fn(vis = "")))]
     pub split_lines_stroke: Option<Stroke>,
     #[builder(setters(option_fn(vis = "")))]
+    pub minor_ticks_show: Option<bool>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub minor_ticks_length: Option<f64>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub minor_ticks_stroke: Option<Stroke>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub minor_ticks_color: Option<Brush>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub minor_split_lines_show: Option<bool>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub minor_split_lines_color: Option<Brush>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub minor_split_lines_stroke: Option<Stroke>,
+    /// Number of parts each interval between major ticks is split into.
+    #[builder(setters(option_fn(vis = "")))]
+    pub minor_split_number: Option<usize>,
+    #[builder(setters(option_fn(vis = "")))]
     pub labels_show: Option<bool>,
     #[builder(setters(option_fn(vis = "")))]
     pub labels_margin: Option<f64>,
@@ -144,6 +228,27 @@ pub struct ValueAxis {
     pub labels_rotation: Option<f64>,
 }
 
+/// Which category labels are drawn.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LabelInterval {
+    /// Hides labels evenly until the remaining ones do not collide.
+    Auto,
+    /// Draws the label of every nth category.
+    Every(usize),
+}
+
+/// Rotation of category labels that would overlap.
+const AUTO_ROTATION: f64 = -f64::consts::FRAC_PI_4;
+
+/// Space kept between two category labels.
+const LABELS_GAP: f64 = 4.0;
+
+/// Category labels that are drawn, after truncation, interval and rotation are applied.
+struct LabelLayout {
+    labels: Vec<(usize, String)>,
+    rotation: Option<f64>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct ValueAxisMeta {
     pub min: f64,
@@ -162,6 +267,98 @@ pub(crate) enum AxisType {
     YAxis,
 }
 
+impl AxisType {
+    fn group(&self, index: usize, helper: &ChartHelper) -> Group {
+        match self {
+            AxisType::XAxis => Group::new(helper.id(&format!("x-axis-{index}")), "axis x-axis"),
+            AxisType::YAxis => Group::new(helper.id(&format!("y-axis-{index}")), "axis y-axis"),
+        }
+    }
+}
+
+/// Distance between the labels margin and the center of an x axis label. Rotated labels are
+/// moved away from the axis so that they do not reach into the plot area.
+fn x_label_center(label: &str, font_size: f64, rotation: Option<f64>, text: &TextMeasurer) -> f64 {
+    match rotation {
+        Some(_) => text.measure_rotated(label, font_size, rotation).height / 2.0,
+        None => font_size / 2.0,
+    }
+}
+
+/// Everything an axis draws outside of the plot area.
+struct AxisFootprint {
+    offset: f64,
+    ticks_length: f64,
+    labels_margin: f64,
+    font_size: f64,
+    rotation: Option<f64>,
+    labels: Vec<String>,
+    /// The first and last label sit on the ends of the axis and overhang the plot area.
+    edge_labels: bool,
+}
+
+impl AxisFootprint {
+    fn extents(
+        &self,
+        axis_type: &AxisType,
+        position: &AxisPosition,
+        text: &TextMeasurer,
+    ) -> Extents {
+        let outward_offset = match (axis_type, position) {
+            (AxisType::XAxis, AxisPosition::Start) | (AxisType::YAxis, AxisPosition::End) => {
+                self.offset
+            }
+            (AxisType::XAxis, AxisPosition::End) | (AxisType::YAxis, AxisPosition::Start) => {
+                -self.offset
+            }
+        }
+        .max(0.0);
+
+        let sizes: Vec<Size> = self
+            .labels
+            .iter()
+            .map(|label| text.measure_rotated(label, self.font_size, self.rotation))
+            .collect();
+        // Labels are rotated around their center and y axis labels are anchored at the margin.
+        let labels_depth = self
+            .labels
+            .iter()
+            .zip(&sizes)
+            .map(|(label, size)| match axis_type {
+                AxisType::XAxis => {
+                    x_label_center(label, self.font_size, self.rotation, text) + size.height / 2.0
+                }
+                AxisType::YAxis => (text.measure(label, self.font_size).width + size.width) / 2.0,
+            })
+            .reduce(f64::max)
+            .map_or(0.0, |depth| self.labels_margin + depth);
+        let depth = outward_offset + labels_depth.max(self.ticks_length);
+
+        let mut extents = Extents::default();
+        match (axis_type, position) {
+            (AxisType::XAxis, AxisPosition::Start) => extents.bottom = depth,
+            (AxisType::XAxis, AxisPosition::End) => extents.top = depth,
+            (AxisType::YAxis, AxisPosition::Start) => extents.left = depth,
+            (AxisType::YAxis, AxisPosition::End) => extents.right = depth,
+        }
+        if self.edge_labels
+            && let (Some(first), Some(last)) = (sizes.first(), sizes.last())
+        {
+            match axis_type {
+                AxisType::XAxis => {
+                    extents.left = extents.left.max(first.width / 2.0);
+                    extents.right = extents.right.max(last.width / 2.0);
+                }
+                AxisType::YAxis => {
+                    extents.bottom = extents.bottom.max(first.height / 2.0);
+                    extents.top = extents.top.max(last.height / 2.0);
+                }
+            }
+        }
+        extents
+    }
+}
+
 impl<'a> CategoryAxis {
     pub(crate) fn draw_axis(
         &'a self,
@@ -171,10 +368,20 @@ impl<'a> CategoryAxis {
         helper: &ChartHelper,
         theme: &'a Theme,
     ) {
-        self.draw_split_lines(axis_type, primitives, helper, theme);
-        self.draw_axis_ticks(index, axis_type, primitives, helper, theme);
-        self.draw_labels(index, axis_type, primitives, helper, theme);
-        self.draw_axis_line(index, axis_type, primitives, helper, theme);
+        grouped(primitives, axis_type.group(index, helper), |primitives| {
+            grouped(primitives, Group::class("split-line"), |primitives| {
+                self.draw_split_lines(axis_type, primitives, helper, theme)
+            });
+            grouped(primitives, Group::class("axis-tick"), |primitives| {
+                self.draw_axis_ticks(index, axis_type, primitives, helper, theme)
+            });
+            grouped(primitives, Group::class("axis-label"), |primitives| {
+                self.draw_labels(index, axis_type, primitives, helper, theme)
+            });
+            grouped(primitives, Group::class("axis-line"), |primitives| {
+                self.draw_axis_line(index, axis_type, primitives, helper, theme)
+            });
+        });
     }
 
     pub(crate) fn draw_axis_line(
@@ -396,26 +603,22 @@ impl<'a> CategoryAxis {
             let labels_margin = self
                 .labels_margin
                 .unwrap_or(theme.cartesian_category_axis.labels_margin);
+            let font_size = self
+                .labels_font_size
+                .unwrap_or(theme.cartesian_category_axis.labels_font_size);
+            let layout = self.label_layout(axis_type, label_spacing, theme, &helper.text);
 
-            for (label_index, label) in self.data.iter().enumerate() {
+            for (label_index, label) in layout.labels {
                 let point = match axis_type {
                     AxisType::XAxis => {
+                        let center =
+                            x_label_center(&label, font_size, layout.rotation, &helper.text);
                         let pos_y = match position {
                             AxisPosition::Start => {
-                                helper.offsets.y_axis_start
-                                    + labels_margin
-                                    + offset
-                                    + self
-                                        .labels_font_size
-                                        .unwrap_or(theme.cartesian_value_axis.labels_font_size)
-                                        / 2.0
+                                helper.offsets.y_axis_start + labels_margin + offset + center
                             }
                             AxisPosition::End => {
-                                helper.offsets.y_axis_end - labels_margin + offset
-                                    - self
-                                        .labels_font_size
-                                        .unwrap_or(theme.cartesian_value_axis.labels_font_size)
-                                        / 2.0
+                                helper.offsets.y_axis_end - labels_margin + offset - center
                             }
                         };
 
@@ -445,23 +648,115 @@ impl<'a> CategoryAxis {
                 });
 
                 let text = crate::primitives::Text {
-                    text: label.to_string(),
+                    text: label,
                     fill_color: self
                         .labels_color
                         .as_ref()
                         .unwrap_or(&theme.cartesian_category_axis.labels_color),
-                    font_size: self
-                        .labels_font_size
-                        .unwrap_or(theme.cartesian_category_axis.labels_font_size),
+                    font_size,
                     text_anchor,
                     coord: point,
-                    rotation: self.labels_rotation,
+                    rotation: layout.rotation,
                 };
                 primitives.push(crate::primitives::Primitives::Text(text));
             }
         }
     }
 
+    fn label_layout(
+        &self,
+        axis_type: &AxisType,
+        spacing: f64,
+        theme: &Theme,
+        text: &TextMeasurer,
+    ) -> LabelLayout {
+        let axis_theme = &theme.cartesian_category_axis;
+        let font_size = self.labels_font_size.unwrap_or(axis_theme.labels_font_size);
+        let labels: Vec<String> = match self.labels_max_width {
+            Some(max_width) => self
+                .data
+                .iter()
+                .map(|label| text.truncate(label, font_size, max_width))
+                .collect(),
+            None => self.data.clone(),
+        };
+
+        // Space a label takes up along the axis, including the gap to its neighbours.
+        let footprint = |rotation: Option<f64>| {
+            labels
+                .iter()
+                .map(|label| match (axis_type, rotation) {
+                    // Rotated labels are parallel to each other and only collide through their height.
+                    (AxisType::XAxis, Some(r)) if r.sin().abs() > 0.1 => {
+                        text.measure(label, font_size).height / r.sin().abs()
+                    }
+                    (AxisType::XAxis, _) => text.measure_rotated(label, font_size, rotation).width,
+                    (AxisType::YAxis, _) => text.measure_rotated(label, font_size, rotation).height,
+                })
+                .fold(0.0, f64::max)
+                + LABELS_GAP
+        };
+
+        let rotation = match self.labels_rotation {
+            Some(rotation) => Some(rotation),
+            None if *axis_type == AxisType::XAxis
+                && self
+                    .labels_auto_rotate
+                    .unwrap_or(axis_theme.labels_auto_rotate)
+                && footprint(None) > spacing =>
+            {
+                Some(AUTO_ROTATION)
+            }
+            None => None,
+        };
+        let interval = match self
+            .labels_interval
+            .as_ref()
+            .unwrap_or(&axis_theme.labels_interval)
+        {
+            LabelInterval::Auto => (footprint(rotation) / spacing).ceil().max(1.0) as usize,
+            LabelInterval::Every(n) => (*n).max(1),
+        };
+
+        LabelLayout {
+            labels: labels.into_iter().enumerate().step_by(interval).collect(),
+            rotation,
+        }
+    }
+
+    pub(crate) fn extents(
+        &self,
+        index: usize,
+        axis_type: &AxisType,
+        theme: &Theme,
+        text: &TextMeasurer,
+        offsets: &Offsets,
+    ) -> Extents {
+        let axis_theme = &theme.cartesian_category_axis;
+        let position = self.get_axis_position(index);
+        let spacing = match axis_type {
+            AxisType::XAxis => offsets.x_span / self.data.len() as f64,
+            AxisType::YAxis => offsets.y_span / self.data.len() as f64,
+        };
+        let layout = self.label_layout(axis_type, spacing, theme, text);
+        let footprint = AxisFootprint {
+            offset: self.get_axis_offset(index, position, theme),
+            ticks_length: match self.ticks_show.unwrap_or(axis_theme.ticks_show) {
+                true => self.ticks_length.unwrap_or(axis_theme.ticks_length),
+                false => 0.0,
+            },
+            labels_margin: self.labels_margin.unwrap_or(axis_theme.labels_margin),
+            font_size: self.labels_font_size.unwrap_or(axis_theme.labels_font_size),
+            rotation: layout.rotation,
+            labels: match self.labels_show.unwrap_or(axis_theme.labels_show) {
+                true => layout.labels.into_iter().map(|(_, label)| label).collect(),
+                false => Vec::new(),
+            },
+            edge_labels: false,
+        };
+        footprint.extents(axis_type, position, text)
+    }
+
     fn get_axis_position(&self, index: usize) -> &AxisPosition {
         self.axis_position.as_ref().unwrap_or({
             if index % 2 == 0 {
@@ -514,70 +809,130 @@ impl<'a> ValueAxis {
         primitives: &mut Vec<Primitives<'a>>,
         helper: &ChartHelper,
         theme: &'a Theme,
-        mut filtered_series: impl Iterator<Item = &'a Series>,
+        filtered_series: impl Iterator<Item = &'a Series>,
         primary: bool,
     ) -> ValueAxisMeta {
-        let (mut min, mut max) = match filtered_series.next() {
-            Some(s) => match s {
-                Series::Line(line) => {
-                    let data_index = match primary {
-                        true => line.data.primary_data_index,
-                        false => line.data.secondary_data_index,
-                    };
-                    get_raw_range(&line.data.data[data_index])
-                }
-                Series::Scatter(scatter) => {
-                    let data_index = match primary {
-                        true => scatter.data.primary_data_index,
-                        false => scatter.data.secondary_data_index,
-                    };
-                    if let PlotData::Float(data) = &scatter.data.plot_data[data_index] {
-                        get_raw_range(data)
-                    } else {
-                        todo!("error msg")
-                    }
-                }
-            },
-            None => unreachable!(),
-        };
+        let scale = self.scale(
+            filtered_series,
+            primary,
+            helper.linked.get(axis_type),
+            helper.dataset,
+        );
+        let (min, max, step_size) = scale;
 
-        for series in filtered_series {
-            let (s_min, s_max) = match series {
-                Series::Line(line) => {
-                    let data_index = match primary {
-                        true => line.data.primary_data_index,
-                        false => line.data.secondary_data_index,
-                    };
-                    get_raw_range(&line.data.data[data_index])
-                }
+        grouped(primitives, axis_type.group(index, helper), |primitives| {
+            grouped(primitives, Group::class("minor-split-line"), |primitives| {
+                self.draw_minor_split_lines(axis_type, primitives, helper, theme, scale)
+            });
+            grouped(primitives, Group::class("split-line"), |primitives| {
+                self.draw_split_lines(axis_type, primitives, helper, theme, min, max, step_size)
+            });
+            grouped(primitives, Group::class("minor-axis-tick"), |primitives| {
+                self.draw_minor_ticks(index, axis_type, primitives, helper, theme, scale)
+            });
+            grouped(primitives, Group::class("axis-tick"), |primitives| {
+                self.draw_axis_ticks(
+                    index, axis_type, primitives, helper, theme, min, max, step_size,
+                )
+            });
+            grouped(primitives, Group::class("axis-label"), |primitives| {
+                self.draw_labels(
+                    index, axis_type, primitives, helper, theme, min, max, step_size,
+                )
+            });
+            grouped(primitives, Group::class("axis-line"), |primitives| {
+                self.draw_axis_line(index, &AxisType::YAxis, primitives, helper, theme)
+            });
+        });
+        ValueAxisMeta { min, max }
+    }
 
-                Series::Scatter(scatter) => {
-                    let data_index = match primary {
-                        true => scatter.data.primary_data_index,
-                        false => scatter.data.secondary_data_index,
-                    };
-                    if let PlotData::Float(data) = &scatter.data.plot_data[data_index] {
-                        get_raw_range(data)
-                    } else {
-                        todo!("error msg")
-                    }
-                }
-            };
-            min = min.min(s_min);
-            max = max.max(s_max);
+    /// Rounded `(min, max, step_size)` of the primary or secondary data of all series,
+    /// widened to the `linked` range of the same axis in other grids.
+    pub(crate) fn scale<'s>(
+        &self,
+        filtered_series: impl Iterator<Item = &'s Series>,
+        primary: bool,
+        linked: Option<(f64, f64)>,
+        dataset: Option<&Dataset>,
+    ) -> (f64, f64, f64) {
+        let (min, max) = match (Self::raw_range(filtered_series, primary, dataset), linked) {
+            (Some((min, max)), Some((linked_min, linked_max))) => {
+                (min.min(linked_min), max.max(linked_max))
+            }
+            (range, linked) => range.or(linked).unwrap_or((f64::NAN, f64::NAN)),
+        };
+        // Without numbers, or with an empty or inverted range, the axis spans one unit.
+        let min = Some(self.min.unwrap_or(min))
+            .filter(|min| min.is_finite())
+            .unwrap_or(0.0);
+        let max = Some(self.max.unwrap_or(max))
+            .filter(|max| max.is_finite() && *max > min)
+            .unwrap_or(min + 1.0);
+        let (nice_min, nice_max, step_size) = match self.time {
+            true => get_time_scale_details(min, max),
+            false => get_scale_details(min, max),
+        };
+        let (min, max) = (
+            self.min.map_or(nice_min, |_| min),
+            self.max.map_or(nice_max, |_| max),
+        );
+        // Intervals that are not positive or would need too many ticks are ignored.
+        let interval = self
+            .interval
+            .filter(|interval| *interval > 0.0 && (max - min) / interval <= MAX_TICKS);
+        (min, max, interval.unwrap_or(step_size))
+    }
+
+    /// Label of the major tick at `value`, `step_size` apart from its neighbours.
+    pub(crate) fn label(&self, value: f64, step_size: f64) -> String {
+        match self.time {
+            true => format_time(value, step_size),
+            false => format!("{value}"),
         }
+    }
 
-        let (min, max, step_size) = get_scale_details(min, max);
+    /// Unrounded `(min, max)` of the primary or secondary data of all series, `None` if no
+    /// series has numeric data. Series reading [unknown
+    /// dimensions](crate::chart::Chart::unknown_dimensions) are skipped, as they are not drawn.
+    pub(crate) fn raw_range<'s>(
+        filtered_series: impl Iterator<Item = &'s Series>,
+        primary: bool,
+        dataset: Option<&Dataset>,
+    ) -> Option<(f64, f64)> {
+        filtered_series
+            .filter_map(|series| Some(series.float_data(primary, dataset)?.range()))
+            .reduce(|(min, max), (s_min, s_max)| (min.min(s_min), max.max(s_max)))
+    }
 
-        self.draw_split_lines(axis_type, primitives, helper, theme, min, max, step_size);
-        self.draw_axis_ticks(
-            index, axis_type, primitives, helper, theme, min, max, step_size,
-        );
-        self.draw_labels(
-            index, axis_type, primitives, helper, theme, min, max, step_size,
-        );
-        self.draw_axis_line(index, &AxisType::YAxis, primitives, helper, theme);
-        ValueAxisMeta { min, max }
+    pub(crate) fn extents(
+        &self,
+        index: usize,
+        axis_type: &AxisType,
+        theme: &Theme,
+        text: &TextMeasurer,
+        (min, max, step_size): (f64, f64, f64),
+    ) -> Extents {
+        let axis_theme = &theme.cartesian_value_axis;
+        let position = self.get_axis_position(index);
+        let footprint = AxisFootprint {
+            offset: self.get_axis_offset(index, position, theme),
+            ticks_length: match self.ticks_show.unwrap_or(axis_theme.ticks_show) {
+                true => self.ticks_length.unwrap_or(axis_theme.ticks_length),
+                false => 0.0,
+            },
+            labels_margin: self.labels_margin.unwrap_or(axis_theme.labels_margin),
+            font_size: self.labels_font_size.unwrap_or(axis_theme.labels_font_size),
+            rotation: self.labels_rotation,
+            labels: match self.labels_show.unwrap_or(axis_theme.labels_show) {
+                true => (0..(((max - min) / step_size) as i32 + 1))
+                    .map(|label_index| self.label(min + step_size * label_index as f64, step_size))
+                    .collect(),
+                false => Vec::new(),
+            },
+            edge_labels: true,
+        };
+        footprint.extents(axis_type, position, text)
     }
 
     pub(crate) fn draw_axis_line(
@@ -785,6 +1140,139 @@ impl<'a> ValueAxis {
         }
     }
 
+    pub(crate) fn draw_minor_ticks(
+        &'a self,
+        index: usize,
+        axis_type: &AxisType,
+        primitives: &mut Vec<Primitives<'a>>,
+        helper: &ChartHelper,
+        theme: &'a Theme,
+        scale: (f64, f64, f64),
+    ) {
+        if self
+            .minor_ticks_show
+            .unwrap_or(theme.cartesian_value_axis.minor_ticks_show)
+        {
+            let position = self.get_axis_position(index);
+            let offset = self.get_axis_offset(index, position, theme);
+            let ticks_length = self
+                .minor_ticks_length
+                .unwrap_or(theme.cartesian_value_axis.minor_ticks_length);
+
+            let meta = ValueAxisMeta {
+                min: scale.0,
+                max: scale.1,
+            };
+            let pos = self.pos_closure(axis_type, &meta, helper);
+            for value in self.minor_values(scale, theme) {
+                let (start_point, end_point) = match axis_type {
+                    AxisType::XAxis => {
+                        let (start_y, end_y) = match position {
+                            AxisPosition::Start => (
+                                helper.offsets.y_axis_start + offset,
+                                helper.offsets.y_axis_start + offset + ticks_length,
+                            ),
+                            AxisPosition::End => (
+                                helper.offsets.y_axis_end + offset,
+                                helper.offsets.y_axis_end + offset - ticks_length,
+                            ),
+                        };
+                        let common_x = pos(None, Some(value));
+                        (Point::new(common_x, start_y), Point::new(common_x, end_y))
+                    }
+                    AxisType::YAxis => {
+                        let (start_x, end_x) = match position {
+                            AxisPosition::Start => (
+                                helper.offsets.x_axis_start + offset,
+                                helper.offsets.x_axis_start + offset - ticks_length,
+                            ),
+                            AxisPosition::End => (
+                                helper.offsets.x_axis_end + offset,
+                                helper.offsets.x_axis_end + offset + ticks_length,
+                            ),
+                        };
+                        let common_y = pos(None, Some(value));
+                        (Point::new(start_x, common_y), Point::new(end_x, common_y))
+                    }
+                };
+                let line = crate::primitives::Line {
+                    stroke: self
+                        .minor_ticks_stroke
+                        .as_ref()
+                        .unwrap_or(&theme.cartesian_value_axis.minor_ticks_stroke),
+                    stroke_color: self
+                        .minor_ticks_color
+                        .as_ref()
+                        .unwrap_or(&theme.cartesian_value_axis.minor_ticks_color),
+                    coords: (start_point, end_point),
+                };
+                primitives.push(crate::primitives::Primitives::Line(line));
+            }
+        }
+    }
+
+    pub(crate) fn draw_minor_split_lines(
+        &'a self,
+        axis_type: &AxisType,
+        primitives: &mut Vec<Primitives<'a>>,
+        helper: &ChartHelper,
+        theme: &'a Theme,
+        scale: (f64, f64, f64),
+    ) {
+        if self
+            .minor_split_lines_show
+            .unwrap_or(theme.cartesian_value_axis.minor_split_lines_show)
+        {
+            let meta = ValueAxisMeta {
+                min: scale.0,
+                max: scale.1,
+            };
+            let pos = self.pos_closure(axis_type, &meta, helper);
+            for value in self.minor_values(scale, theme) {
+                let (start_point, end_point) = match axis_type {
+                    AxisType::XAxis => {
+                        let common_x = pos(None, Some(value));
+                        (
+                            Point::new(common_x, helper.offsets.y_axis_start),
+                            Point::new(common_x, helper.offsets.y_axis_end),
+                        )
+                    }
+                    AxisType::YAxis => {
+                        let common_y = pos(None, Some(value));
+                        (
+                            Point::new(helper.offsets.x_axis_start, common_y),
+                            Point::new(helper.offsets.x_axis_end, common_y),
+                        )
+                    }
+                };
+                let line = crate::primitives::Line {
+                    stroke: self
+                        .minor_split_lines_stroke
+                        .as_ref()
+                        .unwrap_or(&theme.cartesian_value_axis.minor_split_lines_stroke),
+                    stroke_color: self
+                        .minor_split_lines_color
+                        .as_ref()
+                        .unwrap_or(&theme.cartesian_value_axis.minor_split_lines_color),
+                    coords: (start_point, end_point),
+                };
+                primitives.push(crate::primitives::Primitives::Line(line));
+            }
+        }
+    }
+
+    /// Values between the major ticks, placed through [`Self::pos_closure`] like all other values.
+    fn minor_values(&self, (min, max, step_size): (f64, f64, f64), theme: &Theme) -> Vec<f64> {
+        let major: Vec<f64> = (0..(((max - min) / step_size) as i32 + 1))
+            .map(|tick_index| min + step_size * tick_index as f64)
+            .collect();
+        get_minor_values(
+            &major,
+            self.minor_split_number
+                .unwrap_or(theme.cartesian_value_axis.minor_split_number),
+        )
+    }
+
     pub(crate) fn draw_labels(
         &'a self,
         index: usize,
@@ -810,25 +1298,21 @@ impl<'a> ValueAxis {
             let labels_margin = self
                 .labels_margin
                 .unwrap_or(theme.cartesian_value_axis.labels_margin);
+            let font_size = self
+                .labels_font_size
+                .unwrap_or(theme.cartesian_value_axis.labels_font_size);
             for label_index in 0..(((max - min) / step_size) as i32 + 1) {
+                let label = self.label(min + step_size * label_index as f64, step_size);
                 let point = match axis_type {
                     AxisType::XAxis => {
+                        let center =
+                            x_label_center(&label, font_size, self.labels_rotation, &helper.text);
                         let pos_y = match position {
                             AxisPosition::Start => {
-                                helper.offsets.y_axis_start
-                                    + labels_margin
-                                    + offset
-                                    + self
-                                        .labels_font_size
-                                        .unwrap_or(theme.cartesian_value_axis.labels_font_size)
-                                        / 2.0
+                                helper.offsets.y_axis_start + labels_margin + offset + center
                             }
                             AxisPosition::End => {
-                                helper.offsets.y_axis_end - labels_margin + offset
-                                    - self
-                                        .labels_font_size
-                                        .unwrap_or(theme.cartesian_value_axis.labels_font_size)
-                                        / 2.0
+                                helper.offsets.y_axis_end - labels_margin + offset - center
                             }
                         };
 
@@ -857,14 +1341,12 @@ impl<'a> ValueAxis {
                 });
 
                 let text = crate::primitives::Text {
-                    text: format!("{}", min + step_size * label_index as f64),
+                    text: label,
                     fill_color: self
                         .labels_color
                         .as_ref()
                         .unwrap_or(&theme.cartesian_value_axis.labels_color),
-                    font_size: self
-                        .labels_font_size
-                        .unwrap_or(theme.cartesian_value_axis.labels_font_size),
+                    font_size,
                     text_anchor,
                     coord: point,
                     rotation: self.labels_rotation,
@@ -920,3 +1402,73 @@ impl<'a> ValueAxis {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::element::PlotSize;
+
+    fn axis(count: usize) -> CategoryAxis {
+        CategoryAxis::builder()
+            .data((0..count).map(|i| format!("Category {i}")).collect())
+            .build()
+    }
+
+    #[test]
+    fn label_layout() {
+        let theme = Theme::white();
+        let text = TextMeasurer::new();
+
+        let layout = axis(3).label_layout(&AxisType::XAxis, 100.0, &theme, &text);
+        assert_eq!(layout.labels.len(), 3);
+        assert_eq!(layout.rotation, None);
+
+        let layout = axis(200).label_layout(&AxisType::XAxis, 2.0, &theme, &text);
+        assert_eq!(layout.rotation, Some(AUTO_ROTATION));
+        assert!(layout.labels.len() < 40);
+        assert_eq!(layout.labels[0].1, "Category 0");
+
+        let every = CategoryAxis {
+            labels_interval: Some(LabelInterval::Every(10)),
+            labels_auto_rotate: Some(false),
+            labels_max_width: Some(text.measure("Category", 12.0).width),
+            ..axis(200)
+        };
+        let layout = every.label_layout(&AxisType::XAxis, 2.0, &theme, &text);
+        assert_eq!(layout.rotation, None);
+        assert_eq!(layout.labels.len(), 20);
+        assert_eq!(layout.labels[1].0, 10);
+        assert!(layout.labels[1].1.ends_with('…'));
+    }
+
+    #[test]
+    fn minor_split_lines() {
+        let theme = Theme::white();
+        let helper = ChartHelper::with_size(PlotSize::default());
+        let axis = ValueAxis::builder()
+            .minor_split_lines_show(true)
+            .minor_split_number(4)
+            .build();
+
+        assert_eq!(
+            axis.minor_values((0.0, 200.0, 100.0), &theme),
+            [25.0, 50.0, 75.0, 125.0, 150.0, 175.0]
+        );
+
+        let mut primitives = Vec::new();
+        axis.draw_minor_split_lines(
+            &AxisType::YAxis,
+            &mut primitives,
+            &helper,
+            &theme,
+            (0.0, 200.0, 100.0),
+        );
+        assert_eq!(primitives.len(), 6);
+        let Primitives::Line(line) = &primitives[1] else {
+            panic!("expected a line");
+        };
+        let quarter = helper.offsets.y_axis_start - helper.offsets.y_span / 4.0;
+        assert!((line.coords.0.y - quarter).abs() < 1e-9);
+        assert_eq!(line.coords.0.x, helper.offsets.x_axis_start);
+    }
+}
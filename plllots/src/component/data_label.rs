@@ -0,0 +1,183 @@
+use std::{fmt, sync::Arc};
+
+use bon::Builder;
+use kurbo::{Point, Rect, Vec2};
+use parley::Alignment;
+use peniko::Brush;
+
+use crate::{
+    chart::{ChartHelper, Theme},
+    primitives::Primitives,
+};
+
+/// Where a data label is placed relative to its symbol.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LabelPosition {
+    Top,
+    Bottom,
+    Left,
+    Right,
+    /// Centered on the symbol.
+    Inside,
+}
+
+/// Turns a value into the text of its label.
+#[derive(Clone)]
+pub struct LabelFormatter(Arc<dyn Fn(f64) -> String + Send + Sync>);
+
+impl LabelFormatter {
+    pub fn new(f: impl Fn(f64) -> String + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    fn format(&self, value: f64) -> String {
+        (self.0)(value)
+    }
+}
+
+impl<F: Fn(f64) -> String + Send + Sync + 'static> From<F> for LabelFormatter {
+    fn from(f: F) -> Self {
+        Self::new(f)
+    }
+}
+
+impl fmt::Debug for LabelFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LabelFormatter").finish_non_exhaustive()
+    }
+}
+
+/// Formatters are only equal if they are clones of each other.
+impl PartialEq for LabelFormatter {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Draws the value of every data point next to its symbol.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct DataLabel {
+    #[builder(setters(option_fn(vis = "")))]
+    pub position: Option<LabelPosition>,
+    /// Defaults to the value rounded to two decimals.
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub formatter: Option<LabelFormatter>,
+    /// Gap between the symbol and the label.
+    #[builder(setters(option_fn(vis = "")))]
+    pub distance: Option<f64>,
+    /// Shifts the label by `(x, y)` pixels after it is placed.
+    #[builder(with = |x: f64, y: f64| Vec2::new(x, y))]
+    pub offset: Option<Vec2>,
+    /// Rotation in radians around the center of the label.
+    #[builder(setters(option_fn(vis = "")))]
+    pub rotation: Option<f64>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub color: Option<Brush>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub font_size: Option<f64>,
+    /// Skips labels that would overlap a label drawn before them.
+    #[builder(setters(option_fn(vis = "")))]
+    pub hide_overlap: Option<bool>,
+}
+
+impl<'a> DataLabel {
+    /// Draws a label for every `(coord, value, symbol radius)` of a series.
+    pub(crate) fn draw(
+        &'a self,
+        points: impl Iterator<Item = (Point, f64, f64)>,
+        helper: &ChartHelper,
+        primitives: &mut Vec<Primitives<'a>>,
+        theme: &'a Theme,
+    ) {
+        let font_size = self.font_size.unwrap_or(theme.data_label.font_size);
+        let fill_color = self.color.as_ref().unwrap_or(&theme.data_label.color);
+        let hide_overlap = self.hide_overlap.unwrap_or(theme.data_label.hide_overlap);
+        let mut placed: Vec<Rect> = Vec::new();
+
+        for (coord, value, radius) in points {
+            if value.is_nan() {
+                continue;
+            }
+            let text = match &self.formatter {
+                Some(formatter) => formatter.format(value),
+                None => format!("{}", (value * 100.0).round() / 100.0),
+            };
+            let size = helper.text.measure_rotated(&text, font_size, self.rotation);
+            let center = self.center(coord, radius, size, theme);
+            if hide_overlap {
+                let bounds = Rect::from_center_size(center, size);
+                if placed.iter().any(|other| overlaps(other, &bounds)) {
+                    continue;
+                }
+                placed.push(bounds);
+            }
+            primitives.push(Primitives::Text(crate::primitives::Text {
+                text,
+                fill_color,
+                font_size,
+                text_anchor: Alignment::Middle,
+                coord: center,
+                rotation: self.rotation,
+            }));
+        }
+    }
+
+    /// Center of a label of `size` for a symbol of `radius` at `coord`.
+    fn center(&self, coord: Point, radius: f64, size: kurbo::Size, theme: &Theme) -> Point {
+        let gap = radius + self.distance.unwrap_or(theme.data_label.distance);
+        let shift = match self.position.as_ref().unwrap_or(&theme.data_label.position) {
+            LabelPosition::Top => Vec2::new(0.0, -gap - size.height / 2.0),
+            LabelPosition::Bottom => Vec2::new(0.0, gap + size.height / 2.0),
+            LabelPosition::Left => Vec2::new(-gap - size.width / 2.0, 0.0),
+            LabelPosition::Right => Vec2::new(gap + size.width / 2.0, 0.0),
+            LabelPosition::Inside => Vec2::ZERO,
+        };
+        coord + shift + self.offset.unwrap_or(Vec2::ZERO)
+    }
+}
+
+/// Whether two labels overlap, touching edges do not count.
+fn overlaps(a: &Rect, b: &Rect) -> bool {
+    let intersection = a.intersect(*b);
+    intersection.width() > 0.0 && intersection.height() > 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::element::PlotSize;
+
+    #[test]
+    fn hide_overlap() {
+        let helper = ChartHelper::with_size(PlotSize {
+            width: 400.0,
+            height: 300.0,
+        });
+        let theme = Theme::white();
+        let points = || {
+            [0.0, 1.0, 100.0]
+                .into_iter()
+                .map(|x| (Point::new(100.0 + x, 100.0), 1234.0 + x, 2.0))
+        };
+        let count = |label: &DataLabel| {
+            let mut primitives = Vec::new();
+            label.draw(points(), &helper, &mut primitives, &theme);
+            primitives.len()
+        };
+
+        assert_eq!(count(&DataLabel::builder().build()), 3);
+        assert_eq!(count(&DataLabel::builder().hide_overlap(true).build()), 2);
+
+        let label = DataLabel::builder()
+            .formatter(|v: f64| format!("{v} ms"))
+            .position(LabelPosition::Bottom)
+            .build();
+        let mut primitives = Vec::new();
+        label.draw(points().take(1), &helper, &mut primitives, &theme);
+        let Primitives::Text(text) = &primitives[0] else {
+            panic!("expected a text primitive");
+        };
+        assert_eq!(text.text, "1234 ms");
+        assert!(text.coord.y > 100.0 + 2.0 + theme.data_label.distance);
+    }
+}
@@ -0,0 +1,114 @@
+use bon::Builder;
+use kurbo::{Point, Stroke};
+use parley::Alignment;
+use peniko::Brush;
+
+use crate::{
+    chart::{ChartHelper, Theme},
+    component::{AxisType, MarkAxis, MarkValue, SingleCartesianAxis},
+    primitives::Primitives,
+    series::Series,
+};
+
+/// A highlighted region beneath the series, e.g. weekends, an incident window or an
+/// acceptable range.
+///
+/// Without a range on an axis the region spans the whole plot area along it, so setting only
+/// `y` draws a horizontal band.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct MarkArea {
+    #[builder(with = |from: impl Into<MarkValue>, to: impl Into<MarkValue>| (from.into(), to.into()))]
+    pub x: Option<(MarkValue, MarkValue)>,
+    #[builder(with = |from: impl Into<MarkValue>, to: impl Into<MarkValue>| (from.into(), to.into()))]
+    pub y: Option<(MarkValue, MarkValue)>,
+    #[builder(default = 0, setters(option_fn(vis = "")))]
+    pub x_axis_index: usize,
+    #[builder(default = 0, setters(option_fn(vis = "")))]
+    pub y_axis_index: usize,
+    #[builder(setters(option_fn(vis = "")))]
+    pub fill_color: Option<Brush>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub border_stroke: Option<Stroke>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub border_color: Option<Brush>,
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub label: Option<String>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label_color: Option<Brush>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label_font_size: Option<f64>,
+}
+
+impl<'a> MarkArea {
+    /// Draws the region clipped to the plot area, statistics are computed over `series`.
+    pub(crate) fn draw(
+        &'a self,
+        (x_axis, y_axis): (&SingleCartesianAxis, &SingleCartesianAxis),
+        series: &[&Series],
+        helper: &ChartHelper,
+        primitives: &mut Vec<Primitives<'a>>,
+        theme: &'a Theme,
+    ) {
+        let offsets = &helper.offsets;
+        let resolve = |axis: &MarkAxis, (from, to): &(MarkValue, MarkValue)| {
+            Some((
+                from.resolve_for(axis, x_axis, y_axis, series, helper.dataset)?,
+                to.resolve_for(axis, x_axis, y_axis, series, helper.dataset)?,
+            ))
+        };
+        let (x0, x1) = match &self.x {
+            Some(range) => match resolve(&MarkAxis::X, range) {
+                Some((from, to)) => x_axis.range(&AxisType::XAxis, helper, from, to),
+                None => return,
+            },
+            None => (offsets.x_axis_start, offsets.x_axis_end),
+        };
+        let (y0, y1) = match &self.y {
+            Some(range) => match resolve(&MarkAxis::Y, range) {
+                Some((from, to)) => y_axis.range(&AxisType::YAxis, helper, from, to),
+                None => return,
+            },
+            None => (offsets.y_axis_end, offsets.y_axis_start),
+        };
+        let rect = kurbo::Rect::new(x0, y0, x1, y1).intersect(offsets.plot_area());
+        if rect.area() <= 0.0 {
+            return;
+        }
+
+        primitives.push(Primitives::Rect(crate::primitives::Rect {
+            stroke: self
+                .border_stroke
+                .as_ref()
+                .unwrap_or(&theme.mark_area.border_stroke),
+            stroke_color: self
+                .border_color
+                .as_ref()
+                .unwrap_or(&theme.mark_area.border_color),
+            fill_color: self
+                .fill_color
+                .as_ref()
+                .unwrap_or(&theme.mark_area.fill_color),
+            rect,
+        }));
+
+        if let Some(label) = &self.label {
+            let font_size = self
+                .label_font_size
+                .unwrap_or(theme.mark_area.label_font_size);
+            primitives.push(Primitives::Text(crate::primitives::Text {
+                text: label.clone(),
+                fill_color: self
+                    .label_color
+                    .as_ref()
+                    .unwrap_or(&theme.mark_area.label_color),
+                font_size,
+                text_anchor: Alignment::Middle,
+                coord: Point::new(
+                    rect.center().x,
+                    rect.y0 + theme.mark_area.label_margin + font_size / 2.0,
+                ),
+                rotation: None,
+            }));
+        }
+    }
+}
@@ -0,0 +1,293 @@
+use bon::Builder;
+use kurbo::{Point, Stroke};
+use parley::Alignment;
+use peniko::Brush;
+
+use crate::{
+    chart::{ChartHelper, Theme},
+    component::{AxisType, SingleCartesianAxis},
+    element::Extents,
+    primitives::Primitives,
+    series::{
+        Series,
+        data::{Dataset, Numbers},
+    },
+    utils::text::TextMeasurer,
+};
+
+/// Which axis a mark is placed on. A mark on [`MarkAxis::Y`] is a horizontal line.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkAxis {
+    X,
+    Y,
+}
+
+/// A value on an axis, either fixed or computed from the data of the series.
+///
+/// On category axes fixed values are the index of the category, statistics mark the category
+/// of the data point closest to them.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkValue {
+    Fixed(f64),
+    Min,
+    Max,
+    Average,
+    Median,
+    /// Percentile between 0 and 100, linearly interpolated between data points.
+    Percentile(f64),
+}
+
+impl From<f64> for MarkValue {
+    fn from(value: f64) -> Self {
+        Self::Fixed(value)
+    }
+}
+
+impl MarkValue {
+    /// Resolves the value for `data`, ignoring NaN. `None` for statistics of empty data.
+    pub(crate) fn resolve(&self, data: &[f64]) -> Option<f64> {
+        let mut values: Vec<f64> = data.iter().copied().filter(|v| !v.is_nan()).collect();
+        match self {
+            MarkValue::Fixed(value) => return Some(*value),
+            _ if values.is_empty() => return None,
+            _ => (),
+        }
+        values.sort_by(f64::total_cmp);
+        let percentile = |p: f64| {
+            let rank = (p / 100.0).clamp(0.0, 1.0) * (values.len() - 1) as f64;
+            let (lower, upper) = (values[rank.floor() as usize], values[rank.ceil() as usize]);
+            lower + (upper - lower) * rank.fract()
+        };
+        Some(match self {
+            MarkValue::Fixed(_) => unreachable!(),
+            MarkValue::Min => values[0],
+            MarkValue::Max => values[values.len() - 1],
+            MarkValue::Average => values.iter().sum::<f64>() / values.len() as f64,
+            MarkValue::Median => percentile(50.0),
+            MarkValue::Percentile(p) => percentile(*p),
+        })
+    }
+
+    /// Resolves the value on `axis` for all `series`, which are drawn on `x_axis` and `y_axis`.
+    pub(crate) fn resolve_for(
+        &self,
+        axis: &MarkAxis,
+        x_axis: &SingleCartesianAxis,
+        y_axis: &SingleCartesianAxis,
+        series: &[&Series],
+        dataset: Option<&Dataset>,
+    ) -> Option<f64> {
+        let categories = (
+            matches!(x_axis, SingleCartesianAxis::Category(_)),
+            matches!(y_axis, SingleCartesianAxis::Category(_)),
+        );
+        self.resolve_on(axis, categories, series, dataset)
+    }
+
+    /// Resolves the value on `axis` for all `series`, with `categories` telling whether the x
+    /// and y axes are category axes. `None` if both are.
+    pub(crate) fn resolve_on(
+        &self,
+        axis: &MarkAxis,
+        categories: (bool, bool),
+        series: &[&Series],
+        dataset: Option<&Dataset>,
+    ) -> Option<f64> {
+        if let MarkValue::Fixed(value) = self {
+            return Some(*value);
+        }
+        // Mirrors how the series map their data: the value axis of a category chart shows the
+        // primary data, with two value axes the x axis shows the secondary data.
+        let (primary, category) = match (axis, categories) {
+            (_, (true, true)) => return None,
+            (MarkAxis::X, (false, true)) | (MarkAxis::Y, (_, false)) => (true, false),
+            (MarkAxis::X, (false, false)) => (false, false),
+            (MarkAxis::X, (true, _)) | (MarkAxis::Y, (_, true)) => (true, true),
+        };
+        let columns: Vec<&Numbers> = series
+            .iter()
+            .filter_map(|s| s.float_data(primary, dataset))
+            .collect();
+        let data: Vec<f64> = columns.iter().flat_map(|c| c.iter()).collect();
+        let value = self.resolve(&data)?;
+        if !category {
+            return Some(value);
+        }
+        columns
+            .iter()
+            .flat_map(|c| c.iter().enumerate())
+            .filter(|(_, v)| !v.is_nan())
+            .min_by(|(_, a), (_, b)| (a - value).abs().total_cmp(&(b - value).abs()))
+            .map(|(index, _)| index as f64)
+    }
+}
+
+/// A reference line across the plot area, e.g. a threshold or the average of a series.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct MarkLine {
+    pub axis: MarkAxis,
+    #[builder(into)]
+    pub value: MarkValue,
+    /// Axes of the cartesian the line is drawn on, ignored for lines attached to a series.
+    #[builder(default = 0, setters(option_fn(vis = "")))]
+    pub x_axis_index: usize,
+    #[builder(default = 0, setters(option_fn(vis = "")))]
+    pub y_axis_index: usize,
+    #[builder(setters(option_fn(vis = "")))]
+    pub stroke: Option<Stroke>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub color: Option<Brush>,
+    /// Text of the label, defaults to the value of the line.
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub label: Option<String>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label_show: Option<bool>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label_color: Option<Brush>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label_font_size: Option<f64>,
+}
+
+impl<'a> MarkLine {
+    fn label_text(&self, value: f64) -> String {
+        self.label
+            .clone()
+            .unwrap_or_else(|| format!("{}", (value * 100.0).round() / 100.0))
+    }
+
+    /// Space the label of the line at `value` takes outside of the plot area, above it for
+    /// lines on [`MarkAxis::X`] and right of it for lines on [`MarkAxis::Y`].
+    pub(crate) fn extents(&self, value: f64, text: &TextMeasurer, theme: &Theme) -> Extents {
+        let mut extents = Extents::default();
+        if !self.label_show.unwrap_or(theme.mark_line.label_show) {
+            return extents;
+        }
+        let font_size = self
+            .label_font_size
+            .unwrap_or(theme.mark_line.label_font_size);
+        let size = text.measure(&self.label_text(value), font_size);
+        match self.axis {
+            MarkAxis::X => extents.top = theme.mark_line.label_margin + size.height,
+            MarkAxis::Y => extents.right = theme.mark_line.label_margin + size.width,
+        }
+        extents
+    }
+
+    /// Draws the line at the resolved `value`, in `color` unless the line sets its own. Values
+    /// outside of the range of the axis are not drawn.
+    pub(crate) fn draw(
+        &'a self,
+        value: f64,
+        (x_axis, y_axis): (&SingleCartesianAxis, &SingleCartesianAxis),
+        color: &'a Brush,
+        helper: &ChartHelper,
+        primitives: &mut Vec<Primitives<'a>>,
+        theme: &'a Theme,
+    ) {
+        let offsets = &helper.offsets;
+        let font_size = self
+            .label_font_size
+            .unwrap_or(theme.mark_line.label_font_size);
+        let (coords, label_coord, text_anchor) = match self.axis {
+            MarkAxis::X => {
+                let x = x_axis.position(&AxisType::XAxis, helper, value);
+                (
+                    (
+                        Point::new(x, offsets.y_axis_start),
+                        Point::new(x, offsets.y_axis_end),
+                    ),
+                    Point::new(
+                        x,
+                        offsets.y_axis_end - theme.mark_line.label_margin - font_size / 2.0,
+                    ),
+                    Alignment::Middle,
+                )
+            }
+            MarkAxis::Y => {
+                let y = y_axis.position(&AxisType::YAxis, helper, value);
+                (
+                    (
+                        Point::new(offsets.x_axis_start, y),
+                        Point::new(offsets.x_axis_end, y),
+                    ),
+                    Point::new(offsets.x_axis_end + theme.mark_line.label_margin, y),
+                    Alignment::Start,
+                )
+            }
+        };
+        if !offsets.contains(coords.0) {
+            return;
+        }
+        let stroke_color = self.color.as_ref().unwrap_or(color);
+        primitives.push(Primitives::Line(crate::primitives::Line {
+            stroke: self.stroke.as_ref().unwrap_or(&theme.mark_line.stroke),
+            stroke_color,
+            coords,
+        }));
+
+        if self.label_show.unwrap_or(theme.mark_line.label_show) {
+            primitives.push(Primitives::Text(crate::primitives::Text {
+                text: self.label_text(value),
+                fill_color: self.label_color.as_ref().unwrap_or(stroke_color),
+                font_size,
+                text_anchor,
+                coord: label_coord,
+                rotation: None,
+            }));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statistics() {
+        let data = [4.0, 1.0, f64::NAN, 3.0, 2.0];
+
+        assert_eq!(MarkValue::Fixed(7.0).resolve(&[]), Some(7.0));
+        assert_eq!(MarkValue::Min.resolve(&data), Some(1.0));
+        assert_eq!(MarkValue::Max.resolve(&data), Some(4.0));
+        assert_eq!(MarkValue::Average.resolve(&data), Some(2.5));
+        assert_eq!(MarkValue::Median.resolve(&data), Some(2.5));
+        assert_eq!(MarkValue::Percentile(100.0).resolve(&data), Some(4.0));
+        assert_eq!(MarkValue::Percentile(25.0).resolve(&data), Some(1.75));
+        assert_eq!(MarkValue::Average.resolve(&[f64::NAN]), None);
+    }
+
+    #[test]
+    fn category_axis() {
+        let series = Series::from(
+            crate::series::Line::builder()
+                .data(vec![150.0, 230.0, 224.0])
+                .build(),
+        );
+        let resolve = |value: MarkValue, axis, categories| {
+            value.resolve_on(&axis, categories, &[&series], None)
+        };
+
+        assert_eq!(
+            resolve(MarkValue::Max, MarkAxis::X, (true, false)),
+            Some(1.0)
+        );
+        assert_eq!(
+            resolve(MarkValue::Average, MarkAxis::X, (true, false)),
+            Some(2.0)
+        );
+        assert_eq!(
+            resolve(MarkValue::Min, MarkAxis::Y, (true, false)),
+            Some(150.0)
+        );
+        assert_eq!(resolve(MarkValue::Min, MarkAxis::Y, (true, true)), None);
+
+        let theme = Theme::white();
+        let mark_line = MarkLine::builder()
+            .axis(MarkAxis::Y)
+            .value(MarkValue::Max)
+            .build();
+        let extents = mark_line.extents(230.0, &TextMeasurer::new(), &theme);
+        assert!(extents.right > theme.mark_line.label_margin);
+        assert_eq!(extents.top, 0.0);
+    }
+}
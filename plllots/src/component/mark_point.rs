@@ -0,0 +1,292 @@
+use bon::Builder;
+use kurbo::{Point, Stroke, Vec2};
+use parley::Alignment;
+use peniko::Brush;
+
+use crate::{
+    chart::{ChartHelper, Theme},
+    component::{AxisType, SingleCartesianAxis},
+    primitives::Primitives,
+    series::{Series, data::Dataset},
+};
+
+/// Where a mark point is placed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Anchor {
+    /// Data coordinates `(x, y)`, category axes take the index of the category.
+    Data(f64, f64),
+    /// The smallest value of the series.
+    Min,
+    /// The largest value of the series.
+    Max,
+    /// Pixels from the top left corner of the chart.
+    Pixel(f64, f64),
+    /// Percentages of the chart size from the top left corner.
+    Percentage(f64, f64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Marker {
+    /// Only the label, centered on the anchor.
+    None,
+    Pin,
+    Arrow,
+}
+
+/// A marker with a callout label, e.g. a deployment or an incident. Without a marker it is a
+/// free text annotation.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct MarkPoint {
+    pub anchor: Anchor,
+    /// Axes of the cartesian data anchors refer to, ignored for points attached to a series.
+    #[builder(default = 0, setters(option_fn(vis = "")))]
+    pub x_axis_index: usize,
+    #[builder(default = 0, setters(option_fn(vis = "")))]
+    pub y_axis_index: usize,
+    #[builder(setters(option_fn(vis = "")))]
+    pub marker: Option<Marker>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub marker_size: Option<f64>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub color: Option<Brush>,
+    /// Text of the label, defaults to the value for [`Anchor::Min`] and [`Anchor::Max`].
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub label: Option<String>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label_color: Option<Brush>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label_font_size: Option<f64>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label_background: Option<Brush>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label_border_stroke: Option<Stroke>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label_border_color: Option<Brush>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label_padding: Option<f64>,
+}
+
+impl<'a> MarkPoint {
+    /// Draws the point in `color` unless it sets its own, [`Anchor::Min`] and [`Anchor::Max`]
+    /// are searched in `series`.
+    pub(crate) fn draw(
+        &'a self,
+        axes: (&SingleCartesianAxis, &SingleCartesianAxis),
+        series: &[&Series],
+        color: &'a Brush,
+        helper: &ChartHelper,
+        primitives: &mut Vec<Primitives<'a>>,
+        theme: &'a Theme,
+    ) {
+        let Some((point, value)) = self.resolve(axes, series, helper) else {
+            return;
+        };
+        self.draw_at(point, value, color, helper, primitives, theme);
+    }
+
+    /// Whether the anchor is independent of the axes and the data.
+    pub(crate) fn is_fixed(&self) -> bool {
+        matches!(self.anchor, Anchor::Pixel(..) | Anchor::Percentage(..))
+    }
+
+    /// Draws a mark point with a [fixed](Self::is_fixed) anchor, nothing for other anchors.
+    pub(crate) fn draw_fixed(
+        &'a self,
+        color: &'a Brush,
+        helper: &ChartHelper,
+        primitives: &mut Vec<Primitives<'a>>,
+        theme: &'a Theme,
+    ) {
+        if let Some(point) = self.fixed_point(helper) {
+            self.draw_at(point, None, color, helper, primitives, theme);
+        }
+    }
+
+    fn draw_at(
+        &'a self,
+        point: Point,
+        value: Option<f64>,
+        color: &'a Brush,
+        helper: &ChartHelper,
+        primitives: &mut Vec<Primitives<'a>>,
+        theme: &'a Theme,
+    ) {
+        let color = self.color.as_ref().unwrap_or(color);
+        let size = self.marker_size.unwrap_or(theme.mark_point.marker_size);
+        let stroke = &theme.mark_point.marker_stroke;
+
+        // Bottom center of the label.
+        let label_anchor = match self.marker.as_ref().unwrap_or(&theme.mark_point.marker) {
+            Marker::None => None,
+            Marker::Pin => {
+                let radius = size / 2.0;
+                let head = point - Vec2::new(0.0, 2.0 * radius);
+                primitives.push(Primitives::Polygon(crate::primitives::Polygon {
+                    stroke,
+                    stroke_color: color,
+                    fill_color: color,
+                    fill_opacity: 1.0,
+                    coords: vec![
+                        head + Vec2::new(-0.8 * radius, 0.6 * radius),
+                        point,
+                        head + Vec2::new(0.8 * radius, 0.6 * radius),
+                    ],
+                }));
+                primitives.push(Primitives::Circle(crate::primitives::Circle {
+                    stroke,
+                    stroke_color: color,
+                    fill_color: color,
+                    coord: head,
+                    radius,
+                }));
+                Some(head - Vec2::new(0.0, radius))
+            }
+            Marker::Arrow => {
+                let tail = point - Vec2::new(0.0, 3.0 * size);
+                let head = point - Vec2::new(0.0, 0.8 * size);
+                primitives.push(Primitives::Line(crate::primitives::Line {
+                    stroke,
+                    stroke_color: color,
+                    coords: (tail, head),
+                }));
+                primitives.push(Primitives::Polygon(crate::primitives::Polygon {
+                    stroke,
+                    stroke_color: color,
+                    fill_color: color,
+                    fill_opacity: 1.0,
+                    coords: vec![
+                        head - Vec2::new(0.3 * size, 0.0),
+                        point,
+                        head + Vec2::new(0.3 * size, 0.0),
+                    ],
+                }));
+                Some(tail)
+            }
+        };
+
+        let label = match (&self.label, value) {
+            (Some(label), _) => label.clone(),
+            (None, Some(value)) => format!("{}", (value * 100.0).round() / 100.0),
+            (None, None) => return,
+        };
+        let font_size = self
+            .label_font_size
+            .unwrap_or(theme.mark_point.label_font_size);
+        let padding = self.label_padding.unwrap_or(theme.mark_point.label_padding);
+        let text_size = helper.text.measure(&label, font_size);
+        let center = match label_anchor {
+            Some(bottom) => bottom - Vec2::new(0.0, padding + text_size.height / 2.0 + 2.0),
+            None => point,
+        };
+        let background = kurbo::Rect::from_center_size(
+            center,
+            (
+                text_size.width + 2.0 * padding,
+                text_size.height + 2.0 * padding,
+            ),
+        );
+        primitives.push(Primitives::Rect(crate::primitives::Rect {
+            stroke: self
+                .label_border_stroke
+                .as_ref()
+                .unwrap_or(&theme.mark_point.label_border_stroke),
+            stroke_color: self
+                .label_border_color
+                .as_ref()
+                .unwrap_or(&theme.mark_point.label_border_color),
+            fill_color: self
+                .label_background
+                .as_ref()
+                .unwrap_or(&theme.mark_point.label_background),
+            rect: background,
+        }));
+        primitives.push(Primitives::Text(crate::primitives::Text {
+            text: label,
+            fill_color: self
+                .label_color
+                .as_ref()
+                .unwrap_or(&theme.mark_point.label_color),
+            font_size,
+            text_anchor: Alignment::Middle,
+            coord: center,
+            rotation: None,
+        }));
+    }
+
+    /// Pixel position of a [fixed](Self::is_fixed) anchor.
+    fn fixed_point(&self, helper: &ChartHelper) -> Option<Point> {
+        match self.anchor {
+            Anchor::Pixel(x, y) => Some(Point::new(x, y)),
+            Anchor::Percentage(x, y) => {
+                let size = &helper.plot_size;
+                Some(Point::new(size.width * x / 100.0, size.height * y / 100.0))
+            }
+            Anchor::Data(..) | Anchor::Min | Anchor::Max => None,
+        }
+    }
+
+    /// Pixel position of the anchor, and the value on the value axis for [`Anchor::Min`] and
+    /// [`Anchor::Max`].
+    fn resolve(
+        &self,
+        (x_axis, y_axis): (&SingleCartesianAxis, &SingleCartesianAxis),
+        series: &[&Series],
+        helper: &ChartHelper,
+    ) -> Option<(Point, Option<f64>)> {
+        if let Some(point) = self.fixed_point(helper) {
+            return Some((point, None));
+        }
+        let (x, y) = match self.anchor {
+            Anchor::Pixel(..) | Anchor::Percentage(..) => unreachable!("resolved above"),
+            Anchor::Data(x, y) => (x, y),
+            Anchor::Min => extreme((x_axis, y_axis), series, helper.dataset, false)?,
+            Anchor::Max => extreme((x_axis, y_axis), series, helper.dataset, true)?,
+        };
+        let value = match (&self.anchor, x_axis, y_axis) {
+            (Anchor::Data(..), _, _) => None,
+            (_, SingleCartesianAxis::Value(_), SingleCartesianAxis::Category(_)) => Some(x),
+            _ => Some(y),
+        };
+        let point = Point::new(
+            x_axis.position(&AxisType::XAxis, helper, x),
+            y_axis.position(&AxisType::YAxis, helper, y),
+        );
+        // Data outside of the range of the axes has no place in the plot area.
+        helper.offsets.contains(point).then_some((point, value))
+    }
+}
+
+/// Data coordinates of the smallest or largest primary value of all `series`.
+fn extreme(
+    (x_axis, y_axis): (&SingleCartesianAxis, &SingleCartesianAxis),
+    series: &[&Series],
+    dataset: Option<&Dataset>,
+    max: bool,
+) -> Option<(f64, f64)> {
+    let candidates = series.iter().filter_map(|s| {
+        let values = s.float_data(true, dataset)?.iter().enumerate();
+        let values = values.filter(|(_, v)| !v.is_nan());
+        let (index, value) = match max {
+            true => values.max_by(|(_, a), (_, b)| a.total_cmp(b))?,
+            false => values.min_by(|(_, a), (_, b)| a.total_cmp(b))?,
+        };
+        let coords = match (x_axis, y_axis) {
+            (SingleCartesianAxis::Category(_), SingleCartesianAxis::Value(_)) => {
+                (index as f64, value)
+            }
+            (SingleCartesianAxis::Value(_), SingleCartesianAxis::Category(_)) => {
+                (value, index as f64)
+            }
+            (SingleCartesianAxis::Value(_), SingleCartesianAxis::Value(_)) => {
+                (s.float_data(false, dataset)?.get(index)?, value)
+            }
+            (SingleCartesianAxis::Category(_), SingleCartesianAxis::Category(_)) => return None,
+        };
+        Some((value, coords))
+    });
+    let (_, coords) = match max {
+        true => candidates.max_by(|(a, _), (b, _)| a.total_cmp(b))?,
+        false => candidates.min_by(|(a, _), (b, _)| a.total_cmp(b))?,
+    };
+    Some(coords)
+}
@@ -1,3 +1,13 @@
+pub mod aria;
 pub mod axis;
+pub mod data_label;
+pub mod mark_area;
+pub mod mark_line;
+pub mod mark_point;
 
+pub use aria::*;
 pub use axis::*;
+pub use data_label::*;
+pub use mark_area::*;
+pub use mark_line::*;
+pub use mark_point::*;
@@ -2,16 +2,26 @@ use bon::Builder;
 use std::iter::Peekable;
 
 use crate::{
-    chart::Theme,
-    component::{AxisType, CartesianAxis},
-    primitives::AppendPrimitives,
-    series::Series,
+    chart::{ChartHelper, LinkedRanges, Theme},
+    component::{
+        AxisType, CartesianAxis, MarkArea, MarkLine, MarkPoint, SingleCartesianAxis, ValueAxis,
+    },
+    element::{Extents, Offsets},
+    primitives::{AppendPrimitives, Group, Primitives, clipped, grouped},
+    series::{Series, data::Dataset},
+    utils::text::TextMeasurer,
 };
 
 #[derive(Debug, Builder, Clone, PartialEq)]
 pub struct Cartesian {
     #[builder(field)]
     pub series: Vec<Series>,
+    #[builder(field)]
+    pub mark_areas: Vec<MarkArea>,
+    #[builder(field)]
+    pub mark_lines: Vec<MarkLine>,
+    #[builder(field)]
+    pub mark_points: Vec<MarkPoint>,
     #[builder(into)]
     pub x_axis: CartesianAxis,
     #[builder(into)]
@@ -24,15 +34,234 @@ impl Cartesian {
         x_axis_index: Option<usize>,
         y_axis_index: Option<usize>,
         // ) -> impl Iterator<Item = &Series> {
-    ) -> Peekable<impl Iterator<Item = &Series>> {
+    ) -> Peekable<impl Iterator<Item = (usize, &Series)>> {
         self.series
             .iter()
-            .filter(move |s| {
+            .enumerate()
+            .filter(move |(_, s)| {
                 x_axis_index.unwrap_or(s.x_axis_index()) == s.x_axis_index()
                     && y_axis_index.unwrap_or(s.y_axis_index()) == s.y_axis_index()
             })
             .peekable()
     }
+
+    /// Draws the series on one pair of axes with their marks, between the mark areas and the
+    /// other marks of the cartesian on these axes.
+    fn draw_series<'a>(
+        &'a self,
+        (x_axis_index, y_axis_index): (usize, usize),
+        x_axis: &SingleCartesianAxis,
+        y_axis: &SingleCartesianAxis,
+        primitives: &mut Vec<Primitives<'a>>,
+        helper: &ChartHelper,
+        theme: &'a Theme,
+    ) {
+        let drawn_series: Vec<&Series> = self
+            .filtered_series(Some(x_axis_index), Some(y_axis_index))
+            .map(|(_, s)| s)
+            .collect();
+        grouped(primitives, Group::class("mark-area"), |primitives| {
+            for mark_area in self.mark_areas.iter().filter(|mark_area| {
+                mark_area.x_axis_index == x_axis_index && mark_area.y_axis_index == y_axis_index
+            }) {
+                let axes = (x_axis, y_axis);
+                mark_area.draw(axes, &drawn_series, helper, primitives, theme);
+            }
+        });
+
+        for (i, (series_index, s)) in self
+            .filtered_series(Some(x_axis_index), Some(y_axis_index))
+            .enumerate()
+        {
+            grouped(
+                primitives,
+                Group::new(helper.id(&format!("series-{series_index}")), "series"),
+                |primitives| {
+                    let draw = |primitives: &mut Vec<Primitives<'a>>| match s {
+                        Series::Line(line) => {
+                            line.draw_line(i, x_axis, y_axis, helper, primitives, theme)
+                        }
+                        Series::Scatter(scatter) => {
+                            scatter.draw_scatter(i, x_axis, y_axis, helper, primitives, theme)
+                        }
+                    };
+                    // Labels stay readable next to the edges of the plot area.
+                    let labels = match s.clip() {
+                        true => clipped(primitives, helper.offsets.plot_area(), draw),
+                        false => draw(primitives),
+                    };
+                    primitives.extend(labels);
+                    grouped(primitives, Group::class("mark-line"), |primitives| {
+                        for mark_line in s.mark_lines() {
+                            if let Some(value) = mark_line.value.resolve_for(
+                                &mark_line.axis,
+                                x_axis,
+                                y_axis,
+                                &[s],
+                                helper.dataset,
+                            ) {
+                                let color = s.color(i, theme);
+                                let axes = (x_axis, y_axis);
+                                mark_line.draw(value, axes, color, helper, primitives, theme);
+                            }
+                        }
+                    });
+                    grouped(primitives, Group::class("mark-point"), |primitives| {
+                        for mark_point in s.mark_points() {
+                            let (axes, color) = ((x_axis, y_axis), s.color(i, theme));
+                            mark_point.draw(axes, &[s], color, helper, primitives, theme);
+                        }
+                    });
+                },
+            );
+        }
+
+        grouped(primitives, Group::class("mark-line"), |primitives| {
+            for mark_line in self.mark_lines.iter().filter(|mark_line| {
+                mark_line.x_axis_index == x_axis_index && mark_line.y_axis_index == y_axis_index
+            }) {
+                if let Some(value) = mark_line.value.resolve_for(
+                    &mark_line.axis,
+                    x_axis,
+                    y_axis,
+                    &drawn_series,
+                    helper.dataset,
+                ) {
+                    let color = &theme.mark_line.color;
+                    let axes = (x_axis, y_axis);
+                    mark_line.draw(value, axes, color, helper, primitives, theme);
+                }
+            }
+        });
+        grouped(primitives, Group::class("mark-point"), |primitives| {
+            for mark_point in self.mark_points.iter().filter(|mark_point| {
+                !mark_point.is_fixed()
+                    && mark_point.x_axis_index == x_axis_index
+                    && mark_point.y_axis_index == y_axis_index
+            }) {
+                let (axes, color) = ((x_axis, y_axis), &theme.mark_point.color);
+                mark_point.draw(axes, &drawn_series, color, helper, primitives, theme);
+            }
+        });
+    }
+
+    pub(crate) fn extents(
+        &self,
+        theme: &Theme,
+        text: &TextMeasurer,
+        offsets: &Offsets,
+        linked: &LinkedRanges,
+        dataset: Option<&Dataset>,
+    ) -> Extents {
+        let mut extents = self.axis_extents(
+            (&self.x_axis, &AxisType::XAxis),
+            matches!(self.y_axis, CartesianAxis::Category(_)),
+            theme,
+            text,
+            (offsets, linked, dataset),
+        );
+        extents.union(&self.axis_extents(
+            (&self.y_axis, &AxisType::YAxis),
+            true,
+            theme,
+            text,
+            (offsets, linked, dataset),
+        ));
+        extents.union(&self.mark_line_extents(theme, text, dataset));
+        extents
+    }
+
+    /// Space of the labels of all mark lines, which are drawn outside of the plot area.
+    fn mark_line_extents(
+        &self,
+        theme: &Theme,
+        text: &TextMeasurer,
+        dataset: Option<&Dataset>,
+    ) -> Extents {
+        let categories = (
+            matches!(self.x_axis, CartesianAxis::Category(_)),
+            matches!(self.y_axis, CartesianAxis::Category(_)),
+        );
+        let series_lines = self.series.iter().flat_map(|s| {
+            s.mark_lines()
+                .iter()
+                .map(move |mark_line| (mark_line, vec![s]))
+        });
+        let lines = self.mark_lines.iter().map(|mark_line| {
+            let series = self
+                .filtered_series(Some(mark_line.x_axis_index), Some(mark_line.y_axis_index))
+                .map(|(_, s)| s)
+                .collect();
+            (mark_line, series)
+        });
+        let mut extents = Extents::default();
+        for (mark_line, series) in series_lines.chain(lines) {
+            let value = mark_line
+                .value
+                .resolve_on(&mark_line.axis, categories, &series, dataset);
+            if let Some(value) = value {
+                extents.union(&mark_line.extents(value, text, theme));
+            }
+        }
+        extents
+    }
+
+    fn axis_extents(
+        &self,
+        (axis, axis_type): (&CartesianAxis, &AxisType),
+        primary: bool,
+        theme: &Theme,
+        text: &TextMeasurer,
+        (offsets, linked, dataset): (&Offsets, &LinkedRanges, Option<&Dataset>),
+    ) -> Extents {
+        let mut extents = Extents::default();
+        match axis {
+            CartesianAxis::Category(axes) => {
+                for (axis_index, axis) in axes.iter().enumerate() {
+                    extents.union(&axis.extents(axis_index, axis_type, theme, text, offsets));
+                }
+            }
+            CartesianAxis::Value(axes) => {
+                for (axis_index, axis) in axes.iter().enumerate() {
+                    let mut filtered_series = match axis_type {
+                        AxisType::XAxis => self.filtered_series(Some(axis_index), None),
+                        AxisType::YAxis => self.filtered_series(None, Some(axis_index)),
+                    };
+                    if filtered_series.peek().is_none() {
+                        continue;
+                    }
+                    let series = filtered_series.map(|(_, s)| s);
+                    let scale = axis.scale(series, primary, linked.get(axis_type), dataset);
+                    extents.union(&axis.extents(axis_index, axis_type, theme, text, scale));
+                }
+            }
+        }
+        extents
+    }
+
+    /// Raw ranges of the data on the value axes, for linking them with the axes of other grids.
+    pub(crate) fn value_ranges(&self, dataset: Option<&Dataset>) -> LinkedRanges {
+        let range = |axis: &CartesianAxis, axis_type: &AxisType, primary: bool| match axis {
+            CartesianAxis::Category(_) => None,
+            CartesianAxis::Value(axes) => (0..axes.len())
+                .filter_map(|axis_index| {
+                    let filtered_series = match axis_type {
+                        AxisType::XAxis => self.filtered_series(Some(axis_index), None),
+                        AxisType::YAxis => self.filtered_series(None, Some(axis_index)),
+                    };
+                    ValueAxis::raw_range(filtered_series.map(|(_, s)| s), primary, dataset)
+                })
+                .reduce(|(min, max), (s_min, s_max)| (min.min(s_min), max.max(s_max))),
+        };
+        LinkedRanges {
+            x: range(
+                &self.x_axis,
+                &AxisType::XAxis,
+                matches!(self.y_axis, CartesianAxis::Category(_)),
+            ),
+            y: range(&self.y_axis, &AxisType::YAxis, true),
+        }
+    }
 }
 
 impl<S: cartesian_builder::State> CartesianBuilder<S> {
@@ -45,6 +274,21 @@ impl<S: cartesian_builder::State> CartesianBuilder<S> {
         self.series = series.into_iter().map(Into::into).collect();
         self
     }
+
+    pub fn add_mark_area(mut self, mark_area: MarkArea) -> Self {
+        self.mark_areas.push(mark_area);
+        self
+    }
+
+    pub fn add_mark_line(mut self, mark_line: MarkLine) -> Self {
+        self.mark_lines.push(mark_line);
+        self
+    }
+
+    pub fn add_mark_point(mut self, mark_point: MarkPoint) -> Self {
+        self.mark_points.push(mark_point);
+        self
+    }
 }
 
 impl<'a> AppendPrimitives<'a> for Cartesian {
@@ -73,29 +317,17 @@ impl<'a> AppendPrimitives<'a> for Cartesian {
                             primitives,
                             helper,
                             theme,
-                            filtered_series,
+                            filtered_series.map(|(_, s)| s),
                             true,
                         );
-                        let filtered_series =
-                            self.filtered_series(Some(x_axis_index), Some(y_axis_index));
-                        filtered_series.enumerate().for_each(|(i, s)| match s {
-                            Series::Line(line) => line.draw_line(
-                                i,
-                                &x_axis.into(),
-                                &(y_axis, &y_axis_meta).into(),
-                                helper,
-                                primitives,
-                                theme,
-                            ),
-                            Series::Scatter(scatter) => scatter.draw_scatter(
-                                i,
-                                &x_axis.into(),
-                                &(y_axis, &y_axis_meta).into(),
-                                helper,
-                                primitives,
-                                theme,
-                            ),
-                        });
+                        self.draw_series(
+                            (x_axis_index, y_axis_index),
+                            &x_axis.into(),
+                            &(y_axis, &y_axis_meta).into(),
+                            primitives,
+                            helper,
+                            theme,
+                        );
                     }
                 }
             }
@@ -116,29 +348,17 @@ impl<'a> AppendPrimitives<'a> for Cartesian {
                             primitives,
                             helper,
                             theme,
-                            filtered_series,
+                            filtered_series.map(|(_, s)| s),
                             true,
                         );
-                        let filtered_series =
-                            self.filtered_series(Some(x_axis_index), Some(y_axis_index));
-                        filtered_series.enumerate().for_each(|(i, s)| match s {
-                            Series::Line(line) => line.draw_line(
-                                i,
-                                &(x_axis, &x_axis_meta).into(),
-                                &y_axis.into(),
-                                helper,
-                                primitives,
-                                theme,
-                            ),
-                            Series::Scatter(scatter) => scatter.draw_scatter(
-                                i,
-                                &(x_axis, &x_axis_meta).into(),
-                                &y_axis.into(),
-                                helper,
-                                primitives,
-                                theme,
-                            ),
-                        });
+                        self.draw_series(
+                            (x_axis_index, y_axis_index),
+                            &(x_axis, &x_axis_meta).into(),
+                            &y_axis.into(),
+                            primitives,
+                            helper,
+                            theme,
+                        );
                     }
                 }
             }
@@ -156,7 +376,7 @@ impl<'a> AppendPrimitives<'a> for Cartesian {
                         primitives,
                         helper,
                         theme,
-                        filtered_series,
+                        filtered_series.map(|(_, s)| s),
                         false,
                     );
                     for (y_axis_index, y_axis) in y_axes.iter().enumerate() {
@@ -172,32 +392,28 @@ impl<'a> AppendPrimitives<'a> for Cartesian {
                             primitives,
                             helper,
                             theme,
-                            filtered_series,
+                            filtered_series.map(|(_, s)| s),
                             true,
                         );
-                        let filtered_series =
-                            self.filtered_series(Some(x_axis_index), Some(y_axis_index));
-                        filtered_series.enumerate().for_each(|(i, s)| match s {
-                            Series::Line(line) => line.draw_line(
-                                i,
-                                &(x_axis, &x_axis_meta).into(),
-                                &(y_axis, &y_axis_meta).into(),
-                                helper,
-                                primitives,
-                                theme,
-                            ),
-                            Series::Scatter(scatter) => scatter.draw_scatter(
-                                i,
-                                &(x_axis, &x_axis_meta).into(),
-                                &(y_axis, &y_axis_meta).into(),
-                                helper,
-                                primitives,
-                                theme,
-                            ),
-                        });
+                        self.draw_series(
+                            (x_axis_index, y_axis_index),
+                            &(x_axis, &x_axis_meta).into(),
+                            &(y_axis, &y_axis_meta).into(),
+                            primitives,
+                            helper,
+                            theme,
+                        );
                     }
                 }
             }
         }
+
+        // Placed on the chart rather than the axes, so drawn even without any series.
+        grouped(primitives, Group::class("mark-point"), |primitives| {
+            for mark_point in self.mark_points.iter().filter(|m| m.is_fixed()) {
+                let color = &theme.mark_point.color;
+                mark_point.draw_fixed(color, helper, primitives, theme);
+            }
+        });
     }
 }
@@ -1,12 +1,102 @@
 pub mod cartesian;
+pub mod pie;
+pub mod polar;
+pub mod radar;
 
 pub use cartesian::*;
+pub use pie::*;
+pub use polar::*;
+pub use radar::*;
 
-use crate::{chart::Theme, primitives::AppendPrimitives};
+use crate::{
+    chart::{LinkedRanges, Theme},
+    component::{AriaSeries, CartesianAxis},
+    element::{Extents, Offsets},
+    primitives::AppendPrimitives,
+    series::{Series, data::Dataset},
+    utils::text::TextMeasurer,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum CoordinateSystem {
     Cartesian(Cartesian),
+    Pie(Box<Pie>),
+    Polar(Box<Polar>),
+    Radar(Box<Radar>),
+}
+
+impl CoordinateSystem {
+    pub fn series(&self) -> &[Series] {
+        match self {
+            CoordinateSystem::Cartesian(cartesian) => &cartesian.series,
+            CoordinateSystem::Pie(_) | CoordinateSystem::Radar(_) => &[],
+            CoordinateSystem::Polar(polar) => &polar.series,
+        }
+    }
+
+    /// The series of the coordinate system for assistive technology, including pies and
+    /// radar series. `index` is the position of the first one in the chart.
+    pub(crate) fn aria_series(&self, index: usize, dataset: Option<&Dataset>) -> Vec<AriaSeries> {
+        match self {
+            CoordinateSystem::Cartesian(_) | CoordinateSystem::Polar(_) => self
+                .series()
+                .iter()
+                .enumerate()
+                .map(|(i, series)| AriaSeries {
+                    name: series.display_name(index + i),
+                    summary: series.summary(index + i, dataset),
+                    columns: series.columns(index + i, dataset, self.categories(series)),
+                })
+                .collect(),
+            CoordinateSystem::Pie(pie) => vec![pie.aria_series(index)],
+            CoordinateSystem::Radar(radar) => radar.aria_series(index),
+        }
+    }
+
+    /// Labels of the category axis `series` is drawn on, if it has one.
+    fn categories(&self, series: &Series) -> Option<&[String]> {
+        match self {
+            CoordinateSystem::Cartesian(cartesian) => {
+                match (&cartesian.x_axis, &cartesian.y_axis) {
+                    (CartesianAxis::Category(axes), _) => axes.get(series.x_axis_index()),
+                    (_, CartesianAxis::Category(axes)) => axes.get(series.y_axis_index()),
+                    _ => None,
+                }
+                .map(|axis| axis.data.as_slice())
+            }
+            CoordinateSystem::Polar(polar) => match (&polar.angle_axis, &polar.radius_axis) {
+                (PolarAxis::Category(axis), _) | (_, PolarAxis::Category(axis)) => Some(&axis.data),
+                _ => None,
+            },
+            CoordinateSystem::Pie(_) | CoordinateSystem::Radar(_) => None,
+        }
+    }
+
+    pub(crate) fn extents(
+        &self,
+        theme: &Theme,
+        text: &TextMeasurer,
+        offsets: &Offsets,
+        linked: &LinkedRanges,
+        dataset: Option<&Dataset>,
+    ) -> Extents {
+        match self {
+            CoordinateSystem::Cartesian(cartesian) => {
+                cartesian.extents(theme, text, offsets, linked, dataset)
+            }
+            CoordinateSystem::Pie(pie) => pie.extents(theme, text),
+            CoordinateSystem::Polar(polar) => polar.extents(theme, text, dataset),
+            CoordinateSystem::Radar(radar) => radar.extents(theme, text),
+        }
+    }
+
+    /// Raw ranges of the data on the value axes, for linking them with other grids.
+    pub(crate) fn value_ranges(&self, dataset: Option<&Dataset>) -> LinkedRanges {
+        match self {
+            CoordinateSystem::Cartesian(cartesian) => cartesian.value_ranges(dataset),
+            _ => LinkedRanges::default(),
+        }
+    }
 }
 
 impl<'a> AppendPrimitives<'a> for CoordinateSystem {
@@ -20,6 +110,9 @@ impl<'a> AppendPrimitives<'a> for CoordinateSystem {
             CoordinateSystem::Cartesian(cartesian) => {
                 cartesian.append_primitives(primitives, helper, theme);
             }
+            CoordinateSystem::Pie(pie) => pie.append_primitives(primitives, helper, theme),
+            CoordinateSystem::Polar(polar) => polar.append_primitives(primitives, helper, theme),
+            CoordinateSystem::Radar(radar) => radar.append_primitives(primitives, helper, theme),
         }
     }
 }
@@ -0,0 +1,412 @@
+use std::f64::consts::TAU;
+
+use bon::Builder;
+use kurbo::{Stroke, Vec2};
+use parley::Alignment;
+use peniko::Brush;
+
+use crate::{
+    chart::{ChartHelper, Theme},
+    component::AriaSeries,
+    element::Extents,
+    primitives::{AppendPrimitives, Arc, Group, Primitives, grouped},
+    utils::text::TextMeasurer,
+};
+
+/// One share of a [`Pie`]. Negative and NaN values are drawn as empty slices.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PieSlice {
+    pub name: String,
+    pub value: f64,
+    pub color: Option<Brush>,
+}
+
+impl<S: Into<String>> From<(S, f64)> for PieSlice {
+    fn from((name, value): (S, f64)) -> Self {
+        Self {
+            name: name.into(),
+            value,
+            color: None,
+        }
+    }
+}
+
+/// Nightingale rose modes, the radius of a slice grows with its value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rose {
+    /// Angles are proportional to the values as well.
+    Radius,
+    /// All slices have the same angle.
+    Area,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SliceLabelPosition {
+    /// Next to the slice, connected by a leader line.
+    Outside,
+    Inside,
+}
+
+/// Text of the label of a slice.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SliceLabel {
+    Name,
+    Value,
+    Percent,
+    NameAndPercent,
+}
+
+/// A pie chart, or a donut chart with an inner radius.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct Pie {
+    #[builder(field)]
+    pub slices: Vec<PieSlice>,
+    /// Radius of the hole in percent of half the shorter side of the plot area.
+    #[builder(setters(option_fn(vis = "")))]
+    pub inner_radius: Option<f64>,
+    /// Radius in percent of half the shorter side of the plot area.
+    #[builder(setters(option_fn(vis = "")))]
+    pub outer_radius: Option<f64>,
+    /// Where the first slice starts in degrees, counterclockwise from three o'clock.
+    #[builder(setters(option_fn(vis = "")))]
+    pub start_angle: Option<f64>,
+    #[builder(default = true, setters(option_fn(vis = "")))]
+    pub clockwise: bool,
+    /// Gap between neighbouring slices in degrees.
+    #[builder(setters(option_fn(vis = "")))]
+    pub pad_angle: Option<f64>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub rose: Option<Rose>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub border_stroke: Option<Stroke>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub border_color: Option<Brush>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label_show: Option<bool>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label: Option<SliceLabel>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label_position: Option<SliceLabelPosition>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label_color: Option<Brush>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label_font_size: Option<f64>,
+    /// Length of each of the two segments of the leader lines.
+    #[builder(setters(option_fn(vis = "")))]
+    pub leader_length: Option<f64>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub leader_stroke: Option<Stroke>,
+}
+
+impl<S: pie_builder::State> PieBuilder<S> {
+    pub fn add_slice(mut self, slice: impl Into<PieSlice>) -> Self {
+        self.slices.push(slice.into());
+        self
+    }
+
+    pub fn set_slices(mut self, slices: impl IntoIterator<Item: Into<PieSlice>>) -> Self {
+        self.slices = slices.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl<'a> Pie {
+    /// The sectors of all slices, in the order of the slices.
+    fn arcs(&'a self, helper: &ChartHelper, theme: &'a Theme) -> Vec<Arc<'a>> {
+        let offsets = &helper.offsets;
+        let center = offsets.plot_area().center();
+        let base = offsets.x_span.abs().min(offsets.y_span.abs()) / 2.0;
+        let inner_radius = base * self.inner_radius.unwrap_or(theme.pie.inner_radius) / 100.0;
+        let outer_radius = base * self.outer_radius.unwrap_or(theme.pie.outer_radius) / 100.0;
+
+        let values: Vec<f64> = self
+            .slices
+            .iter()
+            .map(|slice| match slice.value.is_nan() {
+                true => 0.0,
+                false => slice.value.max(0.0),
+            })
+            .collect();
+        let total: f64 = values.iter().sum();
+        let max = values.iter().copied().fold(0.0, f64::max);
+        if total <= 0.0 {
+            return Vec::new();
+        }
+
+        let direction = if self.clockwise { 1.0 } else { -1.0 };
+        let pad = self.pad_angle.unwrap_or(theme.pie.pad_angle).to_radians();
+        let mut angle = -self
+            .start_angle
+            .unwrap_or(theme.pie.start_angle)
+            .to_radians();
+        let stroke = self
+            .border_stroke
+            .as_ref()
+            .unwrap_or(&theme.pie.border_stroke);
+        let stroke_color = self
+            .border_color
+            .as_ref()
+            .unwrap_or(&theme.pie.border_color);
+
+        let mut arcs = Vec::with_capacity(self.slices.len());
+        for (index, (slice, value)) in self.slices.iter().zip(values).enumerate() {
+            let sweep = match self.rose {
+                Some(Rose::Area) => TAU / self.slices.len() as f64,
+                _ => TAU * value / total,
+            };
+            let radius = match self.rose {
+                Some(_) => inner_radius + (outer_radius - inner_radius) * value / max,
+                None => outer_radius,
+            };
+            let padding = pad.min(sweep);
+            arcs.push(Arc {
+                stroke,
+                stroke_color,
+                fill_color: slice
+                    .color
+                    .as_ref()
+                    .unwrap_or(&theme.series_colors[index % theme.series_colors.len()]),
+                center,
+                inner_radius,
+                outer_radius: radius,
+                start_angle: angle + direction * padding / 2.0,
+                sweep_angle: direction * (sweep - padding),
+            });
+            angle += direction * sweep;
+        }
+        arcs
+    }
+
+    /// Sum of the values of the drawn slices.
+    fn total(&self) -> f64 {
+        self.slices
+            .iter()
+            .filter(|slice| !slice.value.is_nan())
+            .map(|slice| slice.value.max(0.0))
+            .sum()
+    }
+
+    /// Share of `slice` in `total` in percent, rounded to one decimal place.
+    fn percent(slice: &PieSlice, total: f64) -> f64 {
+        match total > 0.0 {
+            true => (slice.value.max(0.0) / total * 1000.0).round() / 10.0,
+            false => 0.0,
+        }
+    }
+
+    /// The drawn slices as one series named after the position `index` of the pie in the chart.
+    pub(crate) fn aria_series(&self, index: usize) -> AriaSeries {
+        let total = self.total();
+        let name = format!("Series {}", index + 1);
+        let slices: Vec<&PieSlice> = self
+            .slices
+            .iter()
+            .filter(|slice| !slice.value.is_nan())
+            .collect();
+        let shares: Vec<String> = slices
+            .iter()
+            .map(|slice| format!("{} {}%", slice.name, Self::percent(slice, total)))
+            .collect();
+        AriaSeries {
+            summary: format!(
+                "Pie series {} with {} slices: {}.",
+                index + 1,
+                slices.len(),
+                shares.join(", ")
+            ),
+            columns: vec![
+                (
+                    "Name".to_string(),
+                    slices.iter().map(|s| s.name.clone()).collect(),
+                ),
+                (
+                    name.clone(),
+                    slices.iter().map(|s| s.value.to_string()).collect(),
+                ),
+                (
+                    "Percent".to_string(),
+                    slices
+                        .iter()
+                        .map(|s| Self::percent(s, total).to_string())
+                        .collect(),
+                ),
+            ],
+            name,
+        }
+    }
+
+    /// Space of the labels outside of the pie and their leader lines beyond the circle.
+    pub(crate) fn extents(&self, theme: &Theme, text: &TextMeasurer) -> Extents {
+        let position = self
+            .label_position
+            .as_ref()
+            .unwrap_or(&theme.pie.label_position);
+        if !self.label_show.unwrap_or(theme.pie.label_show)
+            || *position == SliceLabelPosition::Inside
+        {
+            return Extents::default();
+        }
+        let total = self.total();
+        let font_size = self.label_font_size.unwrap_or(theme.pie.label_font_size);
+        let (width, height) = self
+            .slices
+            .iter()
+            .filter(|slice| !slice.value.is_nan())
+            .map(|slice| text.measure(&self.label_text(slice, total, theme), font_size))
+            .fold((0.0_f64, 0.0_f64), |(w, h), size| {
+                (w.max(size.width), h.max(size.height))
+            });
+        if width == 0.0 && height == 0.0 {
+            return Extents::default();
+        }
+        // Labels sit beside the end of their leader line and are vertically centered on it.
+        let length = self.leader_length.unwrap_or(theme.pie.leader_length);
+        let horizontal = 2.0 * length + theme.pie.label_gap + width;
+        let vertical = length + height / 2.0;
+        Extents {
+            left: horizontal,
+            top: vertical,
+            right: horizontal,
+            bottom: vertical,
+        }
+    }
+
+    fn label_text(&self, slice: &PieSlice, total: f64, theme: &Theme) -> String {
+        let percent = Self::percent(slice, total);
+        match self.label.as_ref().unwrap_or(&theme.pie.label) {
+            SliceLabel::Name => slice.name.clone(),
+            SliceLabel::Value => format!("{}", (slice.value * 100.0).round() / 100.0),
+            SliceLabel::Percent => format!("{percent}%"),
+            SliceLabel::NameAndPercent => format!("{}: {percent}%", slice.name),
+        }
+    }
+
+    fn draw_labels(
+        &'a self,
+        arcs: &[Arc<'a>],
+        primitives: &mut Vec<Primitives<'a>>,
+        theme: &'a Theme,
+    ) {
+        let total = self.total();
+        let font_size = self.label_font_size.unwrap_or(theme.pie.label_font_size);
+        let fill_color = self.label_color.as_ref().unwrap_or(&theme.pie.label_color);
+        let position = self
+            .label_position
+            .as_ref()
+            .unwrap_or(&theme.pie.label_position);
+        let length = self.leader_length.unwrap_or(theme.pie.leader_length);
+
+        for (slice, arc) in self.slices.iter().zip(arcs) {
+            if arc.sweep_angle == 0.0 {
+                continue;
+            }
+            let text = self.label_text(slice, total, theme);
+            let middle = arc.start_angle + arc.sweep_angle / 2.0;
+            let (coord, text_anchor) = match position {
+                SliceLabelPosition::Inside => {
+                    let radius = (arc.inner_radius + arc.outer_radius) / 2.0;
+                    (arc.point(radius, middle), Alignment::Middle)
+                }
+                SliceLabelPosition::Outside => {
+                    let side = if middle.cos() >= 0.0 { 1.0 } else { -1.0 };
+                    let elbow = arc.point(arc.outer_radius + length, middle);
+                    let end = elbow + Vec2::new(side * length, 0.0);
+                    primitives.push(Primitives::Path(crate::primitives::Path {
+                        stroke: self
+                            .leader_stroke
+                            .as_ref()
+                            .unwrap_or(&theme.pie.leader_stroke),
+                        stroke_color: arc.fill_color,
+                        coords: vec![arc.point(arc.outer_radius, middle), elbow, end],
+                    }));
+                    let anchor = match side > 0.0 {
+                        true => Alignment::Start,
+                        false => Alignment::End,
+                    };
+                    (end + Vec2::new(side * theme.pie.label_gap, 0.0), anchor)
+                }
+            };
+            primitives.push(Primitives::Text(crate::primitives::Text {
+                text,
+                fill_color,
+                font_size,
+                text_anchor,
+                coord,
+                rotation: None,
+            }));
+        }
+    }
+}
+
+impl<'a> AppendPrimitives<'a> for Pie {
+    fn append_primitives(
+        &'a self,
+        primitives: &mut Vec<Primitives<'a>>,
+        helper: &mut ChartHelper,
+        theme: &'a Theme,
+    ) {
+        let arcs = self.arcs(helper, theme);
+        grouped(primitives, Group::class("pie"), |primitives| {
+            for (index, arc) in arcs.iter().enumerate() {
+                grouped(
+                    primitives,
+                    Group::new(helper.id(&format!("slice-{index}")), "slice"),
+                    |primitives| primitives.push(Primitives::Arc(arc.clone())),
+                );
+            }
+        });
+        if self.label_show.unwrap_or(theme.pie.label_show) {
+            grouped(primitives, Group::class("slice-labels"), |primitives| {
+                self.draw_labels(&arcs, primitives, theme);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::element::PlotSize;
+
+    fn helper() -> ChartHelper<'static> {
+        ChartHelper::with_size(PlotSize {
+            width: 400.0,
+            height: 300.0,
+        })
+    }
+
+    #[test]
+    fn sectors() {
+        let theme = Theme::white();
+        let pie = Pie::builder()
+            .add_slice(("Rent", 2.0))
+            .add_slice(("Food", 1.0))
+            .add_slice(("Other", f64::NAN))
+            .add_slice(("Travel", 1.0))
+            .build();
+        let arcs = pie.arcs(&helper(), &theme);
+        let sweeps: Vec<f64> = arcs.iter().map(|arc| arc.sweep_angle / TAU).collect();
+        assert_eq!(sweeps, [0.5, 0.25, 0.0, 0.25]);
+        // Starts at twelve o'clock and continues clockwise.
+        assert!((arcs[0].start_angle + TAU / 4.0).abs() < 1e-9);
+        assert!((arcs[1].start_angle - TAU / 4.0).abs() < 1e-9);
+        // Like in the drawing, the NaN slice is left out of the description.
+        let aria = pie.aria_series(0);
+        assert_eq!(
+            aria.summary,
+            "Pie series 1 with 3 slices: Rent 50%, Food 25%, Travel 25%."
+        );
+        assert_eq!(aria.columns[0].1, ["Rent", "Food", "Travel"]);
+
+        let rose = Pie::builder()
+            .add_slice(("A", 4.0))
+            .add_slice(("B", 1.0))
+            .inner_radius(20.0)
+            .rose(Rose::Area)
+            .build();
+        let arcs = rose.arcs(&helper(), &theme);
+        assert_eq!(arcs[0].sweep_angle, arcs[1].sweep_angle);
+        assert!(arcs[0].outer_radius > arcs[1].outer_radius);
+        assert!(arcs[1].outer_radius > arcs[1].inner_radius);
+    }
+}
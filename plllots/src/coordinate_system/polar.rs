@@ -0,0 +1,613 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+use bon::Builder;
+use kurbo::{Point, Size, Stroke, Vec2};
+use parley::Alignment;
+use peniko::Brush;
+
+use crate::{
+    chart::{ChartHelper, Theme},
+    component::{CategoryAxis, ValueAxis},
+    element::Extents,
+    primitives::{AppendPrimitives, Group, Primitives, grouped},
+    series::{Series, data::Dataset},
+    utils::text::TextMeasurer,
+};
+
+/// Number of segments the circles of the polar are drawn with.
+const CIRCLE_SEGMENTS: usize = 96;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolarAxis {
+    Category(Box<CategoryAxis>),
+    Value(Box<ValueAxis>),
+}
+
+impl From<CategoryAxis> for PolarAxis {
+    fn from(value: CategoryAxis) -> Self {
+        Self::Category(Box::new(value))
+    }
+}
+
+impl From<ValueAxis> for PolarAxis {
+    fn from(value: ValueAxis) -> Self {
+        Self::Value(Box::new(value))
+    }
+}
+
+/// Series drawn around a center, the angle axis runs along the circle and the radius axis
+/// from the center outwards.
+///
+/// The axes take the roles of the x and y axis of a [`super::Cartesian`]: with two value
+/// axes the angle shows the secondary and the radius the primary data. Marks of the series
+/// are not drawn.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct Polar {
+    #[builder(field)]
+    pub series: Vec<Series>,
+    #[builder(into)]
+    pub angle_axis: PolarAxis,
+    #[builder(into)]
+    pub radius_axis: PolarAxis,
+    /// Radius in percent of half the shorter side of the plot area.
+    #[builder(setters(option_fn(vis = "")))]
+    pub radius: Option<f64>,
+    /// Where the angle axis starts in degrees, counterclockwise from three o'clock.
+    #[builder(setters(option_fn(vis = "")))]
+    pub start_angle: Option<f64>,
+    #[builder(default = true, setters(option_fn(vis = "")))]
+    pub clockwise: bool,
+}
+
+impl<S: polar_builder::State> PolarBuilder<S> {
+    pub fn add_series(mut self, series: impl Into<Series>) -> Self {
+        self.series.push(series.into());
+        self
+    }
+
+    pub fn set_series(mut self, series: impl IntoIterator<Item: Into<Series>>) -> Self {
+        self.series = series.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+/// Maps an axis onto `0..=1` of the full turn or of the radius.
+#[derive(Debug, Clone, PartialEq)]
+enum Scale {
+    /// Categories sit on the start of their band along the circle, and in the middle of it
+    /// along the radius.
+    Category {
+        count: usize,
+        centered: bool,
+    },
+    Value {
+        min: f64,
+        max: f64,
+        step: f64,
+    },
+}
+
+impl Scale {
+    /// `None` for a value axis without numeric data.
+    fn new(
+        axis: &PolarAxis,
+        series: &[Series],
+        dataset: Option<&Dataset>,
+        (primary, centered): (bool, bool),
+    ) -> Option<Self> {
+        match axis {
+            PolarAxis::Category(axis) => Some(Scale::Category {
+                count: axis.data.len().max(1),
+                centered,
+            }),
+            PolarAxis::Value(axis) => {
+                let mut series = series
+                    .iter()
+                    .filter(|s| s.float_data(primary, dataset).is_some())
+                    .peekable();
+                series.peek()?;
+                let (min, max, step) = axis.scale(series, primary, None, dataset);
+                Some(Scale::Value { min, max, step })
+            }
+        }
+    }
+
+    fn fraction(&self, index: usize, value: Option<f64>) -> f64 {
+        match *self {
+            Scale::Category { count, centered } => {
+                let offset = if centered { 0.5 } else { 0.0 };
+                (index as f64 + offset) / count as f64
+            }
+            Scale::Value { min, max, .. } => (value.unwrap_or(f64::NAN) - min) / (max - min),
+        }
+    }
+
+    /// Fractions of the ticks with their labels, ticks are placed between the categories.
+    fn ticks(&self, axis: &PolarAxis) -> Vec<(f64, Option<String>)> {
+        match (self, axis) {
+            (Scale::Category { count, centered }, PolarAxis::Category(axis)) => {
+                let offset = if *centered { 0.5 } else { 0.0 };
+                (0..*count)
+                    .map(|i| {
+                        let label = axis.data.get(i).cloned();
+                        ((i as f64 + offset) / *count as f64, label)
+                    })
+                    .collect()
+            }
+            (Scale::Value { min, max, step }, PolarAxis::Value(axis)) => {
+                (0..((max - min) / step) as i32 + 1)
+                    .map(|i| {
+                        let value = min + step * i as f64;
+                        (
+                            self.fraction(0, Some(value)),
+                            Some(axis.label(value, *step)),
+                        )
+                    })
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Fractions of the split lines, on the category boundaries along the radius.
+    fn splits(&self) -> Vec<f64> {
+        match *self {
+            Scale::Category { count, .. } => (1..=count).map(|i| i as f64 / count as f64).collect(),
+            Scale::Value { min, max, step } => (1..((max - min) / step) as i32 + 1)
+                .map(|i| step * i as f64 / (max - min))
+                .collect(),
+        }
+    }
+}
+
+/// Pixel geometry of a circular coordinate system, also used by the radar.
+pub(crate) struct Geometry {
+    pub(crate) center: Point,
+    pub(crate) radius: f64,
+    /// Screen angle of the start of the angle axis in radians, clockwise from three o'clock.
+    pub(crate) start: f64,
+    pub(crate) direction: f64,
+}
+
+impl Geometry {
+    /// Centered in the plot area, `radius` in percent of half its shorter side and
+    /// `start_angle` in degrees counterclockwise from three o'clock.
+    pub(crate) fn new(
+        helper: &ChartHelper,
+        radius: f64,
+        start_angle: f64,
+        clockwise: bool,
+    ) -> Self {
+        let offsets = &helper.offsets;
+        let base = offsets.x_span.abs().min(offsets.y_span.abs()) / 2.0;
+        Geometry {
+            center: offsets.plot_area().center(),
+            radius: base * radius / 100.0,
+            start: -start_angle.to_radians(),
+            direction: if clockwise { 1.0 } else { -1.0 },
+        }
+    }
+
+    pub(crate) fn angle(&self, fraction: f64) -> f64 {
+        self.start + self.direction * fraction * TAU
+    }
+
+    pub(crate) fn point(&self, angle_fraction: f64, radius_fraction: f64) -> Point {
+        self.center + self.radius * radius_fraction * Vec2::from_angle(self.angle(angle_fraction))
+    }
+
+    /// Center of a label of `size` placed `distance` beyond the circle at `angle_fraction`,
+    /// so that no part of the label overlaps the circle.
+    pub(crate) fn label_point(&self, angle_fraction: f64, distance: f64, size: Size) -> Point {
+        let direction = Vec2::from_angle(self.angle(angle_fraction));
+        let half_extent = (direction.x.abs() * size.width + direction.y.abs() * size.height) / 2.0;
+        self.center + (self.radius + distance + half_extent) * direction
+    }
+
+    /// Upper bound of the space labels of `sizes` placed with [`Self::label_point`] take beyond
+    /// a circle of at most half the shorter side of the plot area.
+    pub(crate) fn label_extents(sizes: impl IntoIterator<Item = Size>, distance: f64) -> Extents {
+        let (width, height) = sizes.into_iter().fold((0.0_f64, 0.0_f64), |(w, h), size| {
+            (w.max(size.width), h.max(size.height))
+        });
+        if width == 0.0 && height == 0.0 {
+            return Extents::default();
+        }
+        let (horizontal, vertical) = (
+            distance + width + height / 4.0,
+            distance + height + width / 4.0,
+        );
+        Extents {
+            left: horizontal,
+            top: vertical,
+            right: horizontal,
+            bottom: vertical,
+        }
+    }
+
+    pub(crate) fn circle(&self, radius_fraction: f64) -> Vec<Point> {
+        (0..=CIRCLE_SEGMENTS)
+            .map(|i| self.point(i as f64 / CIRCLE_SEGMENTS as f64, radius_fraction))
+            .collect()
+    }
+}
+
+/// Styles of an axis, resolved against the theme of its kind.
+struct AxisStyle<'a> {
+    axis_show: bool,
+    axis_stroke: &'a Stroke,
+    axis_color: &'a Brush,
+    ticks_show: bool,
+    ticks_length: f64,
+    ticks_stroke: &'a Stroke,
+    ticks_color: &'a Brush,
+    split_lines_show: bool,
+    split_lines_stroke: &'a Stroke,
+    split_lines_color: &'a Brush,
+    labels_show: bool,
+    labels_margin: f64,
+    labels_color: &'a Brush,
+    labels_font_size: f64,
+}
+
+impl PolarAxis {
+    fn style<'a>(&'a self, theme: &'a Theme) -> AxisStyle<'a> {
+        macro_rules! style {
+            ($axis:expr, $theme:expr) => {
+                AxisStyle {
+                    axis_show: $axis.axis_show.unwrap_or($theme.axis_show),
+                    axis_stroke: $axis.axis_stroke.as_ref().unwrap_or(&$theme.axis_stroke),
+                    axis_color: $axis.axis_color.as_ref().unwrap_or(&$theme.axis_color),
+                    ticks_show: $axis.ticks_show.unwrap_or($theme.ticks_show),
+                    ticks_length: $axis.ticks_length.unwrap_or($theme.ticks_length),
+                    ticks_stroke: $axis.ticks_stroke.as_ref().unwrap_or(&$theme.ticks_stroke),
+                    ticks_color: $axis.ticks_color.as_ref().unwrap_or(&$theme.ticks_color),
+                    split_lines_show: $axis.split_lines_show.unwrap_or($theme.split_lines_show),
+                    split_lines_stroke: $axis
+                        .split_lines_stroke
+                        .as_ref()
+                        .unwrap_or(&$theme.split_lines_stroke),
+                    split_lines_color: $axis
+                        .split_lines_color
+                        .as_ref()
+                        .unwrap_or(&$theme.split_lines_color),
+                    labels_show: $axis.labels_show.unwrap_or($theme.labels_show),
+                    labels_margin: $axis.labels_margin.unwrap_or($theme.labels_margin),
+                    labels_color: $axis.labels_color.as_ref().unwrap_or(&$theme.labels_color),
+                    labels_font_size: $axis.labels_font_size.unwrap_or($theme.labels_font_size),
+                }
+            };
+        }
+        match self {
+            PolarAxis::Category(axis) => style!(axis, theme.cartesian_category_axis),
+            PolarAxis::Value(axis) => style!(axis, theme.cartesian_value_axis),
+        }
+    }
+}
+
+impl<'a> Polar {
+    fn geometry(&self, helper: &ChartHelper, theme: &Theme) -> Geometry {
+        Geometry::new(
+            helper,
+            self.radius.unwrap_or(theme.polar.radius),
+            self.start_angle.unwrap_or(theme.polar.start_angle),
+            self.clockwise,
+        )
+    }
+
+    /// Space of the ticks and labels of the angle axis beyond the circle.
+    pub(crate) fn extents(
+        &self,
+        theme: &Theme,
+        text: &TextMeasurer,
+        dataset: Option<&Dataset>,
+    ) -> Extents {
+        let Some((scale, _)) = self.scales(dataset) else {
+            return Extents::default();
+        };
+        let style = self.angle_axis.style(theme);
+        let mut extents = match style.labels_show {
+            true => Geometry::label_extents(
+                scale
+                    .ticks(&self.angle_axis)
+                    .into_iter()
+                    .filter_map(|(_, label)| Some(text.measure(&label?, style.labels_font_size))),
+                style.labels_margin,
+            ),
+            false => Extents::default(),
+        };
+        if style.ticks_show {
+            let length = style.ticks_length;
+            extents.union(&Extents {
+                left: length,
+                top: length,
+                right: length,
+                bottom: length,
+            });
+        }
+        extents
+    }
+
+    /// Scales of the angle and the radius axis, `None` if a value axis has no data.
+    fn scales(&self, dataset: Option<&Dataset>) -> Option<(Scale, Scale)> {
+        let angle_primary = !matches!(
+            (&self.angle_axis, &self.radius_axis),
+            (PolarAxis::Value(_), PolarAxis::Value(_))
+        );
+        Some((
+            Scale::new(
+                &self.angle_axis,
+                &self.series,
+                dataset,
+                (angle_primary, false),
+            )?,
+            Scale::new(&self.radius_axis, &self.series, dataset, (true, true))?,
+        ))
+    }
+
+    fn draw_angle_axis(
+        &'a self,
+        (geometry, scale): (&Geometry, &Scale),
+        primitives: &mut Vec<Primitives<'a>>,
+        helper: &ChartHelper,
+        theme: &'a Theme,
+    ) {
+        let style = self.angle_axis.style(theme);
+        // The last tick of a value axis closes the circle on top of the first one.
+        let ticks: Vec<(f64, Option<String>)> = scale
+            .ticks(&self.angle_axis)
+            .into_iter()
+            .filter(|(fraction, _)| *fraction < 1.0 - 1e-9)
+            .collect();
+
+        grouped(
+            primitives,
+            Group::new(helper.id("angle-axis-0"), "axis angle-axis"),
+            |primitives| {
+                if style.split_lines_show {
+                    grouped(primitives, Group::class("split-line"), |primitives| {
+                        for (fraction, _) in &ticks {
+                            primitives.push(Primitives::Line(crate::primitives::Line {
+                                stroke: style.split_lines_stroke,
+                                stroke_color: style.split_lines_color,
+                                coords: (geometry.center, geometry.point(*fraction, 1.0)),
+                            }));
+                        }
+                    });
+                }
+                if style.axis_show {
+                    grouped(primitives, Group::class("axis-line"), |primitives| {
+                        primitives.push(Primitives::Path(crate::primitives::Path {
+                            stroke: style.axis_stroke,
+                            stroke_color: style.axis_color,
+                            coords: geometry.circle(1.0),
+                        }));
+                    });
+                }
+                let outside = |fraction: f64, distance: f64| {
+                    geometry.center
+                        + (geometry.radius + distance) * Vec2::from_angle(geometry.angle(fraction))
+                };
+                if style.ticks_show {
+                    grouped(primitives, Group::class("axis-tick"), |primitives| {
+                        for (fraction, _) in &ticks {
+                            primitives.push(Primitives::Line(crate::primitives::Line {
+                                stroke: style.ticks_stroke,
+                                stroke_color: style.ticks_color,
+                                coords: (
+                                    geometry.point(*fraction, 1.0),
+                                    outside(*fraction, style.ticks_length),
+                                ),
+                            }));
+                        }
+                    });
+                }
+                if style.labels_show {
+                    grouped(primitives, Group::class("axis-label"), |primitives| {
+                        for (fraction, label) in ticks {
+                            let Some(label) = label else { continue };
+                            let size = helper.text.measure(&label, style.labels_font_size);
+                            primitives.push(Primitives::Text(crate::primitives::Text {
+                                text: label,
+                                fill_color: style.labels_color,
+                                font_size: style.labels_font_size,
+                                text_anchor: Alignment::Middle,
+                                coord: geometry.label_point(fraction, style.labels_margin, size),
+                                rotation: None,
+                            }));
+                        }
+                    });
+                }
+            },
+        );
+    }
+
+    fn draw_radius_axis(
+        &'a self,
+        (geometry, scale): (&Geometry, &Scale),
+        primitives: &mut Vec<Primitives<'a>>,
+        helper: &ChartHelper,
+        theme: &'a Theme,
+    ) {
+        let style = self.radius_axis.style(theme);
+        let ticks = scale.ticks(&self.radius_axis);
+        // Ticks and labels point away from the first sector of the angle axis.
+        let normal = Vec2::from_angle(geometry.start - geometry.direction * FRAC_PI_2);
+
+        grouped(
+            primitives,
+            Group::new(helper.id("radius-axis-0"), "axis radius-axis"),
+            |primitives| {
+                if style.split_lines_show {
+                    grouped(primitives, Group::class("split-line"), |primitives| {
+                        for fraction in scale.splits() {
+                            primitives.push(Primitives::Path(crate::primitives::Path {
+                                stroke: style.split_lines_stroke,
+                                stroke_color: style.split_lines_color,
+                                coords: geometry.circle(fraction),
+                            }));
+                        }
+                    });
+                }
+                if style.axis_show {
+                    grouped(primitives, Group::class("axis-line"), |primitives| {
+                        primitives.push(Primitives::Line(crate::primitives::Line {
+                            stroke: style.axis_stroke,
+                            stroke_color: style.axis_color,
+                            coords: (geometry.center, geometry.point(0.0, 1.0)),
+                        }));
+                    });
+                }
+                if style.ticks_show {
+                    grouped(primitives, Group::class("axis-tick"), |primitives| {
+                        for (fraction, _) in &ticks {
+                            let point = geometry.point(0.0, *fraction);
+                            primitives.push(Primitives::Line(crate::primitives::Line {
+                                stroke: style.ticks_stroke,
+                                stroke_color: style.ticks_color,
+                                coords: (point, point + normal * style.ticks_length),
+                            }));
+                        }
+                    });
+                }
+                if style.labels_show {
+                    grouped(primitives, Group::class("axis-label"), |primitives| {
+                        for (fraction, label) in ticks {
+                            let Some(label) = label else { continue };
+                            let size = helper.text.measure(&label, style.labels_font_size);
+                            let half_extent =
+                                (normal.x.abs() * size.width + normal.y.abs() * size.height) / 2.0;
+                            let distance = style.labels_margin + half_extent;
+                            primitives.push(Primitives::Text(crate::primitives::Text {
+                                text: label,
+                                fill_color: style.labels_color,
+                                font_size: style.labels_font_size,
+                                text_anchor: Alignment::Middle,
+                                coord: geometry.point(0.0, fraction) + normal * distance,
+                                rotation: None,
+                            }));
+                        }
+                    });
+                }
+            },
+        );
+    }
+}
+
+impl<'a> AppendPrimitives<'a> for Polar {
+    fn append_primitives(
+        &'a self,
+        primitives: &mut Vec<Primitives<'a>>,
+        helper: &mut ChartHelper,
+        theme: &'a Theme,
+    ) {
+        let Some((angle_scale, radius_scale)) = self.scales(helper.dataset) else {
+            return;
+        };
+        let geometry = self.geometry(helper, theme);
+        self.draw_radius_axis((&geometry, &radius_scale), primitives, helper, theme);
+        self.draw_angle_axis((&geometry, &angle_scale), primitives, helper, theme);
+
+        let secondary = match (&self.angle_axis, &self.radius_axis) {
+            (PolarAxis::Category(_), PolarAxis::Category(_)) => return,
+            (PolarAxis::Value(_), PolarAxis::Value(_)) => true,
+            _ => false,
+        };
+        let angle_is_category = matches!(self.angle_axis, PolarAxis::Category(_));
+        let coord = |index: usize, secondary_value: Option<f64>, primary_value: Option<f64>| {
+            let (angle, radius) = match (angle_is_category, secondary) {
+                (true, _) => (angle_scale.fraction(index, None), primary_value),
+                (false, true) => (angle_scale.fraction(index, secondary_value), primary_value),
+                (false, false) => {
+                    let angle = angle_scale.fraction(index, primary_value);
+                    return geometry.point(angle, radius_scale.fraction(index, None));
+                }
+            };
+            geometry.point(angle, radius_scale.fraction(index, radius))
+        };
+        for (series_index, s) in self.series.iter().enumerate() {
+            grouped(
+                primitives,
+                Group::new(helper.id(&format!("series-{series_index}")), "series"),
+                |primitives| s.draw(series_index, &coord, secondary, helper, primitives, theme),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::series::Line;
+
+    #[test]
+    fn scales() {
+        let polar = Polar::builder()
+            .angle_axis(
+                CategoryAxis::builder()
+                    .data(bon::vec!["N", "E", "S", "W"])
+                    .build(),
+            )
+            .radius_axis(
+                ValueAxis::builder()
+                    .min(0.0)
+                    .max(10.0)
+                    .interval(5.0)
+                    .build(),
+            )
+            .add_series(Line::builder().data(vec![10.0, 5.0, 0.0, 2.5]).build())
+            .build();
+        let (angle, radius) = polar.scales(None).unwrap();
+
+        assert_eq!(angle.fraction(1, None), 0.25);
+        assert_eq!(radius.fraction(0, Some(2.5)), 0.25);
+        assert_eq!(radius.splits(), [0.5, 1.0]);
+        let labels: Vec<_> = angle
+            .ticks(&polar.angle_axis)
+            .into_iter()
+            .map(|t| t.1)
+            .collect();
+        assert_eq!(labels[3].as_deref(), Some("W"));
+
+        let geometry = Geometry {
+            center: Point::new(100.0, 100.0),
+            radius: 50.0,
+            start: -FRAC_PI_2,
+            direction: 1.0,
+        };
+        // East is a quarter turn clockwise from north.
+        let east = geometry.point(0.25, 1.0);
+        assert!((east.x - 150.0).abs() < 1e-9 && (east.y - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn degenerate_value_axis() {
+        let polar = Polar::builder()
+            .angle_axis(ValueAxis::builder().interval(0.0).build())
+            .radius_axis(ValueAxis::builder().min(5.0).max(5.0).build())
+            .build();
+        assert_eq!(polar.scales(None), None);
+
+        let polar = Polar {
+            series: vec![
+                Line::builder()
+                    .data(vec![vec![0.0, 0.0], vec![1.0, 2.0]])
+                    .build()
+                    .into(),
+            ],
+            ..polar
+        };
+        let (angle, radius) = polar.scales(None).unwrap();
+        assert!(matches!(radius, Scale::Value { min: 5.0, max: 6.0, step } if step > 0.0));
+        // The zero interval falls back to the rounded step of the data.
+        assert_eq!(
+            angle,
+            Scale::Value {
+                min: 0.0,
+                max: 2.5,
+                step: 0.5
+            }
+        );
+        assert_eq!(angle.ticks(&polar.angle_axis).len(), 6);
+    }
+}
@@ -0,0 +1,385 @@
+use bon::Builder;
+use kurbo::{Point, Stroke};
+use parley::Alignment;
+use peniko::Brush;
+
+use crate::{
+    chart::{ChartHelper, Theme},
+    component::AriaSeries,
+    coordinate_system::polar::Geometry,
+    element::Extents,
+    primitives::{AppendPrimitives, Group, MultiSymbol, Primitives, grouped},
+    series::RadarSeries,
+    utils::text::TextMeasurer,
+};
+
+/// One spoke of a [`Radar`], values from `min` to `max` run from the center outwards.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Indicator {
+    pub name: String,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl<S: Into<String>> From<(S, f64)> for Indicator {
+    fn from((name, max): (S, f64)) -> Self {
+        Self {
+            name: name.into(),
+            min: 0.0,
+            max,
+        }
+    }
+}
+
+impl Indicator {
+    /// Position of `value` between the center and the end of the spoke, clamped to the
+    /// spoke. NaN values and all values of an empty range sit on the center.
+    fn fraction(&self, value: f64) -> f64 {
+        match value.is_nan() || self.max <= self.min {
+            true => 0.0,
+            false => ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// Outline of the grid of a [`Radar`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum RadarShape {
+    /// Straight lines between neighbouring spokes.
+    Polygon,
+    Circle,
+}
+
+/// A radar or spider chart comparing several metrics, each on a spoke with its own range.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct Radar {
+    #[builder(field)]
+    pub indicators: Vec<Indicator>,
+    #[builder(field)]
+    pub series: Vec<RadarSeries>,
+    /// Radius in percent of half the shorter side of the plot area.
+    #[builder(setters(option_fn(vis = "")))]
+    pub radius: Option<f64>,
+    /// Direction of the first spoke in degrees, counterclockwise from three o'clock.
+    #[builder(setters(option_fn(vis = "")))]
+    pub start_angle: Option<f64>,
+    #[builder(default = true, setters(option_fn(vis = "")))]
+    pub clockwise: bool,
+    #[builder(setters(option_fn(vis = "")))]
+    pub shape: Option<RadarShape>,
+    /// Number of rings the grid is divided into.
+    #[builder(setters(option_fn(vis = "")))]
+    pub split_number: Option<usize>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub axis_stroke: Option<Stroke>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub axis_color: Option<Brush>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub split_lines_stroke: Option<Stroke>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub split_lines_color: Option<Brush>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub labels_show: Option<bool>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub labels_margin: Option<f64>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub labels_color: Option<Brush>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub labels_font_size: Option<f64>,
+}
+
+impl<S: radar_builder::State> RadarBuilder<S> {
+    pub fn add_indicator(mut self, indicator: impl Into<Indicator>) -> Self {
+        self.indicators.push(indicator.into());
+        self
+    }
+
+    pub fn set_indicators(mut self, indicators: impl IntoIterator<Item: Into<Indicator>>) -> Self {
+        self.indicators = indicators.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn add_series(mut self, series: RadarSeries) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    pub fn set_series(mut self, series: impl IntoIterator<Item = RadarSeries>) -> Self {
+        self.series = series.into_iter().collect();
+        self
+    }
+}
+
+impl<'a> Radar {
+    fn geometry(&self, helper: &ChartHelper, theme: &Theme) -> Geometry {
+        Geometry::new(
+            helper,
+            self.radius.unwrap_or(theme.radar.radius),
+            self.start_angle.unwrap_or(theme.radar.start_angle),
+            self.clockwise,
+        )
+    }
+
+    /// Space of the names of the indicators beyond the circle.
+    pub(crate) fn extents(&self, theme: &Theme, text: &TextMeasurer) -> Extents {
+        if !self.labels_show.unwrap_or(theme.radar.labels_show) {
+            return Extents::default();
+        }
+        let font_size = self
+            .labels_font_size
+            .unwrap_or(theme.radar.labels_font_size);
+        Geometry::label_extents(
+            self.indicators
+                .iter()
+                .map(|indicator| text.measure(&indicator.name, font_size)),
+            self.labels_margin.unwrap_or(theme.radar.labels_margin),
+        )
+    }
+
+    /// Angle fraction of the spoke of the indicator at `index`.
+    fn spoke(&self, index: usize) -> f64 {
+        index as f64 / self.indicators.len() as f64
+    }
+
+    /// The corners of the polygon of a row, one per indicator.
+    fn row_points(&self, geometry: &Geometry, row: &[f64]) -> Vec<Point> {
+        self.indicators
+            .iter()
+            .enumerate()
+            .map(|(index, indicator)| {
+                let value = row.get(index).copied().unwrap_or(f64::NAN);
+                geometry.point(self.spoke(index), indicator.fraction(value))
+            })
+            .collect()
+    }
+
+    /// The radar series, the first one at position `index` among all series of the chart.
+    /// Their tables have a row per indicator and a column per row of values.
+    pub(crate) fn aria_series(&self, index: usize) -> Vec<AriaSeries> {
+        let indicators: Vec<String> = self.indicators.iter().map(|i| i.name.clone()).collect();
+        self.series
+            .iter()
+            .enumerate()
+            .map(|(i, series)| {
+                let name = match &series.name {
+                    Some(name) => name.clone(),
+                    None => format!("Series {}", index + i + 1),
+                };
+                let summary = match &series.name {
+                    Some(name) => format!("Radar series \"{name}\""),
+                    None => format!("Radar series {}", index + i + 1),
+                };
+                let rows = series.data.iter().enumerate().map(|(row_index, row)| {
+                    let header = match series.data.len() {
+                        1 => name.clone(),
+                        _ => format!("{name} {}", row_index + 1),
+                    };
+                    (header, row.iter().map(f64::to_string).collect())
+                });
+                AriaSeries {
+                    summary: format!(
+                        "{summary} with {} rows over {} indicators.",
+                        series.data.len(),
+                        indicators.len()
+                    ),
+                    columns: [("Indicator".to_string(), indicators.clone())]
+                        .into_iter()
+                        .chain(rows)
+                        .collect(),
+                    name,
+                }
+            })
+            .collect()
+    }
+
+    fn draw_grid(
+        &'a self,
+        geometry: &Geometry,
+        primitives: &mut Vec<Primitives<'a>>,
+        helper: &ChartHelper,
+        theme: &'a Theme,
+    ) {
+        let split_number = self.split_number.unwrap_or(theme.radar.split_number).max(1);
+        let shape = self.shape.as_ref().unwrap_or(&theme.radar.shape);
+
+        grouped(primitives, Group::class("split-line"), |primitives| {
+            for split in 1..=split_number {
+                let fraction = split as f64 / split_number as f64;
+                let coords = match shape {
+                    RadarShape::Circle => geometry.circle(fraction),
+                    RadarShape::Polygon => (0..=self.indicators.len())
+                        .map(|index| geometry.point(self.spoke(index), fraction))
+                        .collect(),
+                };
+                primitives.push(Primitives::Path(crate::primitives::Path {
+                    stroke: self
+                        .split_lines_stroke
+                        .as_ref()
+                        .unwrap_or(&theme.radar.split_lines_stroke),
+                    stroke_color: self
+                        .split_lines_color
+                        .as_ref()
+                        .unwrap_or(&theme.radar.split_lines_color),
+                    coords,
+                }));
+            }
+        });
+        grouped(primitives, Group::class("axis-line"), |primitives| {
+            for index in 0..self.indicators.len() {
+                primitives.push(Primitives::Line(crate::primitives::Line {
+                    stroke: self
+                        .axis_stroke
+                        .as_ref()
+                        .unwrap_or(&theme.radar.axis_stroke),
+                    stroke_color: self.axis_color.as_ref().unwrap_or(&theme.radar.axis_color),
+                    coords: (geometry.center, geometry.point(self.spoke(index), 1.0)),
+                }));
+            }
+        });
+        if self.labels_show.unwrap_or(theme.radar.labels_show) {
+            let font_size = self
+                .labels_font_size
+                .unwrap_or(theme.radar.labels_font_size);
+            let margin = self.labels_margin.unwrap_or(theme.radar.labels_margin);
+            grouped(primitives, Group::class("axis-label"), |primitives| {
+                for (index, indicator) in self.indicators.iter().enumerate() {
+                    let size = helper.text.measure(&indicator.name, font_size);
+                    primitives.push(Primitives::Text(crate::primitives::Text {
+                        text: indicator.name.clone(),
+                        fill_color: self
+                            .labels_color
+                            .as_ref()
+                            .unwrap_or(&theme.radar.labels_color),
+                        font_size,
+                        text_anchor: Alignment::Middle,
+                        coord: geometry.label_point(self.spoke(index), margin, size),
+                        rotation: None,
+                    }));
+                }
+            });
+        }
+    }
+
+    fn draw_series(
+        &'a self,
+        geometry: &Geometry,
+        primitives: &mut Vec<Primitives<'a>>,
+        helper: &ChartHelper,
+        theme: &'a Theme,
+    ) {
+        let mut color_index = 0;
+        for (series_index, series) in self.series.iter().enumerate() {
+            let stroke = series.stroke.as_ref().unwrap_or(&theme.line.stroke);
+            let fill_opacity = match series.area_show.unwrap_or(theme.radar.area_show) {
+                true => series.area_opacity.unwrap_or(theme.radar.area_opacity),
+                false => 0.0,
+            };
+            let symbol_show = series.symbol_show.unwrap_or(theme.line.symbol_show);
+            let symbol = series.symbol.as_ref().unwrap_or(&theme.line.symbol);
+            let symbol_size = series.symbol_size.unwrap_or(theme.line.symbol_size);
+
+            grouped(
+                primitives,
+                Group::new(helper.id(&format!("series-{series_index}")), "series"),
+                |primitives| {
+                    for row in &series.data {
+                        let color = series.color.as_ref().unwrap_or(
+                            &theme.series_colors[color_index % theme.series_colors.len()],
+                        );
+                        color_index += 1;
+                        let coords = self.row_points(geometry, row);
+                        primitives.push(Primitives::Polygon(crate::primitives::Polygon {
+                            stroke,
+                            stroke_color: color,
+                            fill_color: color,
+                            fill_opacity,
+                            coords: coords.clone(),
+                        }));
+                        if symbol_show {
+                            primitives.push(
+                                MultiSymbol {
+                                    stroke: &theme.line.symbol_stroke,
+                                    stroke_color: color,
+                                    fill_color: &theme.line.symbol_fill_color,
+                                    symbol,
+                                    rotation: None,
+                                    coords,
+                                    radius: symbol_size,
+                                }
+                                .into_primitive(),
+                            );
+                        }
+                    }
+                },
+            );
+        }
+    }
+}
+
+impl<'a> AppendPrimitives<'a> for Radar {
+    fn append_primitives(
+        &'a self,
+        primitives: &mut Vec<Primitives<'a>>,
+        helper: &mut ChartHelper,
+        theme: &'a Theme,
+    ) {
+        if self.indicators.is_empty() {
+            return;
+        }
+        let geometry = self.geometry(helper, theme);
+        grouped(
+            primitives,
+            Group::new(helper.id("radar-0"), "axis radar"),
+            |primitives| {
+                self.draw_grid(&geometry, primitives, helper, theme);
+            },
+        );
+        self.draw_series(&geometry, primitives, helper, theme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn row_points() {
+        let radar = Radar::builder()
+            .add_indicator(("Latency", 200.0))
+            .add_indicator(Indicator {
+                name: "Availability".to_string(),
+                min: 90.0,
+                max: 100.0,
+            })
+            .add_indicator(("Errors", 10.0))
+            .add_indicator(("Load", 1.0))
+            .build();
+        let geometry = Geometry {
+            center: Point::new(100.0, 100.0),
+            radius: 50.0,
+            start: -std::f64::consts::FRAC_PI_2,
+            direction: 1.0,
+        };
+        let points = radar.row_points(&geometry, &[100.0, 95.0, f64::NAN]);
+
+        // Half of the first spoke, pointing up.
+        assert!((points[0].x - 100.0).abs() < 1e-9 && (points[0].y - 75.0).abs() < 1e-9);
+        // The second spoke points right and starts at its minimum.
+        assert!((points[1].x - 125.0).abs() < 1e-9 && (points[1].y - 100.0).abs() < 1e-9);
+        // Missing and NaN values sit on the center.
+        assert_eq!(points[2], geometry.center);
+        assert_eq!(points[3], geometry.center);
+    }
+
+    #[test]
+    fn fraction() {
+        let errors = Indicator::from(("Errors", 0.0));
+        assert_eq!(errors.fraction(0.0), 0.0);
+        assert_eq!(errors.fraction(3.0), 0.0);
+
+        let load = Indicator::from(("Load", 1.0));
+        assert_eq!(load.fraction(0.5), 0.5);
+        assert_eq!(load.fraction(2.0), 1.0);
+        assert_eq!(load.fraction(-1.0), 0.0);
+    }
+}
@@ -5,8 +5,13 @@ use bon::Builder;
 pub enum MarginType {
     Pixel(f64),
     Percentage(f64),
+    /// Just wide enough for the axis ticks and labels drawn on this side.
+    Auto,
 }
 
+/// Space kept between automatically sized plot areas and the chart border.
+const AUTO_MARGIN_PADDING: f64 = 10.0;
+
 #[derive(Debug, Builder, Clone, PartialEq)]
 pub struct Margins {
     pub left: MarginType,
@@ -26,6 +31,42 @@ impl Default for Margins {
     }
 }
 
+impl Margins {
+    /// Margins that are all [`MarginType::Auto`].
+    pub fn auto() -> Self {
+        Self {
+            left: MarginType::Auto,
+            top: MarginType::Auto,
+            right: MarginType::Auto,
+            bottom: MarginType::Auto,
+        }
+    }
+
+    pub(crate) fn has_auto(&self) -> bool {
+        [&self.left, &self.top, &self.right, &self.bottom]
+            .iter()
+            .any(|margin| **margin == MarginType::Auto)
+    }
+}
+
+/// Space the axes need outside of the plot area on each side, in pixels.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Extents {
+    pub left: f64,
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+}
+
+impl Extents {
+    pub(crate) fn union(&mut self, other: &Extents) {
+        self.left = self.left.max(other.left);
+        self.top = self.top.max(other.top);
+        self.right = self.right.max(other.right);
+        self.bottom = self.bottom.max(other.bottom);
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Offsets {
     pub x_axis_start: f64,
@@ -37,25 +78,28 @@ pub struct Offsets {
 }
 
 impl Offsets {
+    /// Offsets for fixed margins, [`MarginType::Auto`] only keeps the padding.
     pub fn from_margin(plot_size: &PlotSize, margins: &Margins) -> Offsets {
-        let x_axis_start = match margins.left {
+        Self::with_extents(plot_size, margins, &Extents::default())
+    }
+
+    pub(crate) fn with_extents(
+        plot_size: &PlotSize,
+        margins: &Margins,
+        extents: &Extents,
+    ) -> Offsets {
+        let resolve = |margin: &MarginType, length: f64, extent: f64| match *margin {
             MarginType::Pixel(pixel) => pixel,
-            MarginType::Percentage(perc) => plot_size.width * (perc / 100.0),
-        };
-        let x_axis_end = match margins.right {
-            MarginType::Pixel(pixel) => plot_size.width - pixel,
-            MarginType::Percentage(perc) => plot_size.width - plot_size.width * (perc / 100.0),
+            MarginType::Percentage(perc) => length * (perc / 100.0),
+            MarginType::Auto => extent + AUTO_MARGIN_PADDING,
         };
+        let x_axis_start = resolve(&margins.left, plot_size.width, extents.left);
+        let x_axis_end = plot_size.width - resolve(&margins.right, plot_size.width, extents.right);
         let x_span = x_axis_end - x_axis_start;
 
-        let y_axis_start = match margins.top {
-            MarginType::Pixel(pixel) => pixel,
-            MarginType::Percentage(perc) => plot_size.height * (perc / 100.0),
-        };
-        let y_axis_end = match margins.bottom {
-            MarginType::Pixel(pixel) => plot_size.height - pixel,
-            MarginType::Percentage(perc) => plot_size.height - plot_size.height * (perc / 100.0),
-        };
+        let y_axis_start = resolve(&margins.top, plot_size.height, extents.top);
+        let y_axis_end =
+            plot_size.height - resolve(&margins.bottom, plot_size.height, extents.bottom);
         let y_span = y_axis_end - y_axis_start;
 
         Offsets {
@@ -67,4 +111,36 @@ impl Offsets {
             y_span,
         }
     }
+
+    /// Offsets inside of `rect` of the chart instead of the whole chart.
+    pub(crate) fn within(rect: kurbo::Rect, margins: &Margins, extents: &Extents) -> Offsets {
+        let size = PlotSize {
+            width: rect.width(),
+            height: rect.height(),
+        };
+        let offsets = Self::with_extents(&size, margins, extents);
+        Offsets {
+            x_axis_start: offsets.x_axis_start + rect.x0,
+            x_axis_end: offsets.x_axis_end + rect.x0,
+            y_axis_start: offsets.y_axis_start + rect.y0,
+            y_axis_end: offsets.y_axis_end + rect.y0,
+            ..offsets
+        }
+    }
+
+    /// The rectangle between the axes.
+    pub(crate) fn plot_area(&self) -> kurbo::Rect {
+        kurbo::Rect::new(
+            self.x_axis_start,
+            self.y_axis_end,
+            self.x_axis_end,
+            self.y_axis_start,
+        )
+    }
+
+    /// Whether `point` lies in the plot area, including its edges up to rounding errors.
+    pub(crate) fn contains(&self, point: kurbo::Point) -> bool {
+        let area = self.plot_area().inflate(1e-6, 1e-6);
+        (area.x0..=area.x1).contains(&point.x) && (area.y0..=area.y1).contains(&point.y)
+    }
 }
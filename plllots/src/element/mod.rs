@@ -1,5 +1,7 @@
 pub mod margin;
 pub mod size;
+pub mod symbol;
 
 pub use margin::*;
 pub use size::*;
+pub use symbol::*;
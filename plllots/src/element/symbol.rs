@@ -0,0 +1,141 @@
+use std::f64::consts::{FRAC_PI_4, PI};
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use kurbo::{Affine, BezPath, Point, Shape, SvgParseError};
+
+/// Shape drawn at the data points of a series.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Symbol {
+    Circle,
+    Square,
+    Triangle,
+    Diamond,
+    /// A diagonal cross.
+    Cross,
+    Plus,
+    Star,
+    /// A triangular arrow head pointing up, rotate it to point elsewhere.
+    Arrow,
+    /// An outline in a box from -1 to 1, scaled by the symbol size, see [`Symbol::from_svg`].
+    Path(BezPath),
+}
+
+impl Symbol {
+    /// A [`Symbol::Path`] from SVG path data, failing if the data is invalid.
+    pub fn from_svg(data: &str) -> Result<Self, SvgParseError> {
+        BezPath::from_svg(data).map(Symbol::Path)
+    }
+
+    /// Outline centered on the origin, `radius` is half the width of the bounding box.
+    /// `rotation` is in radians, clockwise.
+    pub(crate) fn path(&self, radius: f64, rotation: Option<f64>) -> BezPath {
+        let polygon = |points: &[(f64, f64)]| {
+            let mut path = BezPath::new();
+            for (index, (x, y)) in points.iter().enumerate() {
+                let point = Point::new(x * radius, y * radius);
+                match index {
+                    0 => path.move_to(point),
+                    _ => path.line_to(point),
+                }
+            }
+            path.close_path();
+            path
+        };
+        let plus = [
+            (-1.0 / 3.0, -1.0),
+            (1.0 / 3.0, -1.0),
+            (1.0 / 3.0, -1.0 / 3.0),
+            (1.0, -1.0 / 3.0),
+            (1.0, 1.0 / 3.0),
+            (1.0 / 3.0, 1.0 / 3.0),
+            (1.0 / 3.0, 1.0),
+            (-1.0 / 3.0, 1.0),
+            (-1.0 / 3.0, 1.0 / 3.0),
+            (-1.0, 1.0 / 3.0),
+            (-1.0, -1.0 / 3.0),
+            (-1.0 / 3.0, -1.0 / 3.0),
+        ];
+
+        let mut path = match self {
+            Symbol::Circle => kurbo::Circle::new(Point::ZERO, radius).into_path(0.1),
+            Symbol::Square => polygon(&[(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]),
+            Symbol::Triangle => polygon(&[(0.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]),
+            Symbol::Diamond => polygon(&[(0.0, -1.0), (1.0, 0.0), (0.0, 1.0), (-1.0, 0.0)]),
+            Symbol::Cross => {
+                let mut path = polygon(&plus);
+                path.apply_affine(Affine::rotate(FRAC_PI_4));
+                path
+            }
+            Symbol::Plus => polygon(&plus),
+            Symbol::Star => {
+                let points: Vec<(f64, f64)> = (0..10)
+                    .map(|i| {
+                        let angle = i as f64 * PI / 5.0;
+                        let r = if i % 2 == 0 { 1.0 } else { 0.4 };
+                        (r * angle.sin(), -r * angle.cos())
+                    })
+                    .collect();
+                polygon(&points)
+            }
+            Symbol::Arrow => polygon(&[(0.0, -1.0), (1.0, 1.0), (0.0, 0.4), (-1.0, 1.0)]),
+            Symbol::Path(path) => Affine::scale(radius) * path.clone(),
+        };
+        if let Some(rotation) = rotation {
+            path.apply_affine(Affine::rotate(rotation));
+        }
+        path
+    }
+
+    /// Short name that identifies the shape, e.g. for reusable SVG definitions.
+    pub(crate) fn name(&self) -> String {
+        match self {
+            Symbol::Circle => "circle".to_string(),
+            Symbol::Square => "square".to_string(),
+            Symbol::Triangle => "triangle".to_string(),
+            Symbol::Diamond => "diamond".to_string(),
+            Symbol::Cross => "cross".to_string(),
+            Symbol::Plus => "plus".to_string(),
+            Symbol::Star => "star".to_string(),
+            Symbol::Arrow => "arrow".to_string(),
+            Symbol::Path(path) => {
+                let mut hasher = DefaultHasher::new();
+                path.to_svg().hash(&mut hasher);
+                format!("path-{:x}", hasher.finish())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounding_boxes() {
+        for symbol in [
+            Symbol::Square,
+            Symbol::Triangle,
+            Symbol::Diamond,
+            Symbol::Plus,
+        ] {
+            let bounds = symbol.path(5.0, None).bounding_box();
+            assert_eq!(
+                (bounds.width(), bounds.height()),
+                (10.0, 10.0),
+                "{symbol:?}"
+            );
+        }
+
+        let custom = Symbol::from_svg("M -1 -1 L 1 1").unwrap();
+        let bounds = custom.path(5.0, None).bounding_box();
+        assert_eq!((bounds.x0, bounds.y1), (-5.0, 5.0));
+
+        // Rotated clockwise by a quarter turn the arrow points right.
+        let arrow = Symbol::Arrow.path(5.0, Some(PI / 2.0));
+        let Some(kurbo::PathEl::MoveTo(tip)) = arrow.elements().first() else {
+            panic!("expected the path to start at the tip");
+        };
+        assert!((tip.x - 5.0).abs() < 1e-9 && tip.y.abs() < 1e-9);
+        assert!(Symbol::from_svg("not a path").is_err());
+    }
+}
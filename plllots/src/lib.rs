@@ -84,6 +84,9 @@ mod tests {
                                 primary_data_index: 0,
                                 secondary_data_index: 1,
                                 symbol_size_index: Some(2),
+                                x: None,
+                                y: None,
+                                size: None,
                                 plot_data: vec![
                                     vec![150.0, 230.0, 224.0, 218.0, 135.0, 147.0, 260.0].into(),
                                     vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0].into(),
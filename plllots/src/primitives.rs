@@ -4,7 +4,7 @@ use peniko::Brush;
 
 use crate::{
     chart::Theme,
-    renderer::{AppendSvg, AppendVello},
+    renderer::{AppendSvg, AppendVello, SvgContext},
 };
 
 #[derive(Debug, Clone)]
@@ -14,16 +14,32 @@ pub enum Primitives<'a> {
     Path(Path<'a>),
     Circle(Circle<'a>),
     MultiCircle(MultiCircle<'a>),
+    MultiSymbol(MultiSymbol<'a>),
+    Rect(Rect<'a>),
+    Polygon(Polygon<'a>),
+    Arc(Arc<'a>),
+    PushGroup(Group),
+    PopGroup,
+    PushClip(Clip),
+    PopClip,
 }
 
 impl AppendSvg for Primitives<'_> {
-    fn append_svg(&self, doc: &mut svg::Document) {
+    fn append_svg(&self, svg: &mut SvgContext) {
         match self {
-            Primitives::Line(line) => line.append_svg(doc),
-            Primitives::Text(text) => text.append_svg(doc),
-            Primitives::Path(path) => path.append_svg(doc),
-            Primitives::Circle(circle) => circle.append_svg(doc),
-            Primitives::MultiCircle(multi_circle) => multi_circle.append_svg(doc),
+            Primitives::Line(line) => line.append_svg(svg),
+            Primitives::Text(text) => text.append_svg(svg),
+            Primitives::Path(path) => path.append_svg(svg),
+            Primitives::Circle(circle) => circle.append_svg(svg),
+            Primitives::MultiCircle(multi_circle) => multi_circle.append_svg(svg),
+            Primitives::MultiSymbol(multi_symbol) => multi_symbol.append_svg(svg),
+            Primitives::Rect(rect) => rect.append_svg(svg),
+            Primitives::Polygon(polygon) => polygon.append_svg(svg),
+            Primitives::Arc(arc) => arc.append_svg(svg),
+            Primitives::PushGroup(group) => svg.push_group(group),
+            Primitives::PopGroup => svg.pop_group(),
+            Primitives::PushClip(clip) => svg.push_clip(clip),
+            Primitives::PopClip => svg.pop_group(),
         }
     }
 }
@@ -40,10 +56,41 @@ impl AppendVello for Primitives<'_> {
             Primitives::Path(path) => path.append_vello(scene, vello_render),
             Primitives::Circle(circle) => circle.append_vello(scene, vello_render),
             Primitives::MultiCircle(multi_circle) => multi_circle.append_vello(scene, vello_render),
+            Primitives::MultiSymbol(multi_symbol) => multi_symbol.append_vello(scene, vello_render),
+            Primitives::Rect(rect) => rect.append_vello(scene, vello_render),
+            Primitives::Polygon(polygon) => polygon.append_vello(scene, vello_render),
+            Primitives::Arc(arc) => arc.append_vello(scene, vello_render),
+            Primitives::PushClip(clip) => clip.append_vello(scene, vello_render),
+            Primitives::PopClip => scene.pop_layer(),
+            Primitives::PushGroup(_) | Primitives::PopGroup => (),
         }
     }
 }
 
+/// Runs `draw` between a [`Primitives::PushGroup`] and [`Primitives::PopGroup`] pair.
+pub(crate) fn grouped<'a, R>(
+    primitives: &mut Vec<Primitives<'a>>,
+    group: Group,
+    draw: impl FnOnce(&mut Vec<Primitives<'a>>) -> R,
+) -> R {
+    primitives.push(Primitives::PushGroup(group));
+    let result = draw(primitives);
+    primitives.push(Primitives::PopGroup);
+    result
+}
+
+/// Runs `draw` between a [`Primitives::PushClip`] to `rect` and a [`Primitives::PopClip`].
+pub(crate) fn clipped<'a, R>(
+    primitives: &mut Vec<Primitives<'a>>,
+    rect: kurbo::Rect,
+    draw: impl FnOnce(&mut Vec<Primitives<'a>>) -> R,
+) -> R {
+    primitives.push(Primitives::PushClip(Clip { rect }));
+    let result = draw(primitives);
+    primitives.push(Primitives::PopClip);
+    result
+}
+
 pub trait AppendPrimitives<'a> {
     fn append_primitives(
         &'a self,
@@ -94,3 +141,133 @@ pub struct MultiCircle<'a> {
     pub coords: Vec<Point>,
     pub radius: f64,
 }
+
+/// The same `symbol` drawn at each of `coords`.
+#[derive(Debug, Clone)]
+pub struct MultiSymbol<'a> {
+    pub stroke: &'a Stroke,
+    pub stroke_color: &'a Brush,
+    pub fill_color: &'a Brush,
+    pub symbol: &'a crate::element::Symbol,
+    pub rotation: Option<f64>,
+    pub coords: Vec<Point>,
+    pub radius: f64,
+}
+
+impl<'a> MultiSymbol<'a> {
+    /// Circles become a [`MultiCircle`], which renderers draw natively.
+    pub(crate) fn into_primitive(self) -> Primitives<'a> {
+        match self.symbol {
+            crate::element::Symbol::Circle => Primitives::MultiCircle(MultiCircle {
+                stroke: self.stroke,
+                stroke_color: self.stroke_color,
+                fill_color: self.fill_color,
+                coords: self.coords,
+                radius: self.radius,
+            }),
+            _ => Primitives::MultiSymbol(self),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Rect<'a> {
+    pub stroke: &'a Stroke,
+    pub stroke_color: &'a Brush,
+    pub fill_color: &'a Brush,
+    pub rect: kurbo::Rect,
+}
+
+/// A closed and filled shape through `coords`.
+#[derive(Debug, Clone)]
+pub struct Polygon<'a> {
+    pub stroke: &'a Stroke,
+    pub stroke_color: &'a Brush,
+    pub fill_color: &'a Brush,
+    /// Multiplied with the alpha of `fill_color`, e.g. for translucent areas in a series color.
+    pub fill_opacity: f32,
+    pub coords: Vec<Point>,
+}
+
+/// A sector of a ring between `inner_radius` and `outer_radius`, a pie slice if the inner
+/// radius is zero. Angles are in radians, clockwise from the positive x axis.
+#[derive(Debug, Clone)]
+pub struct Arc<'a> {
+    pub stroke: &'a Stroke,
+    pub stroke_color: &'a Brush,
+    pub fill_color: &'a Brush,
+    pub center: Point,
+    pub inner_radius: f64,
+    pub outer_radius: f64,
+    pub start_angle: f64,
+    pub sweep_angle: f64,
+}
+
+impl Arc<'_> {
+    /// Point at `radius` from the center in the direction of `angle`.
+    pub(crate) fn point(&self, radius: f64, angle: f64) -> Point {
+        self.center + radius * kurbo::Vec2::from_angle(angle)
+    }
+
+    /// Whether the sector is a whole disc or ring, which has no radial edges.
+    pub(crate) fn is_full_turn(&self) -> bool {
+        self.sweep_angle.abs() >= std::f64::consts::TAU - 1e-9
+    }
+
+    /// Outline of the sector, approximated with curves. A full ring is drawn as two circles
+    /// in opposite directions, so that the inner one is a hole.
+    pub(crate) fn path(&self) -> kurbo::BezPath {
+        let arc = |radius: f64, start_angle: f64, sweep_angle: f64| kurbo::Arc {
+            center: self.center,
+            radii: kurbo::Vec2::new(radius, radius),
+            start_angle,
+            sweep_angle,
+            x_rotation: 0.0,
+        };
+        let end_angle = self.start_angle + self.sweep_angle;
+        let mut path = kurbo::BezPath::new();
+        path.move_to(self.point(self.outer_radius, self.start_angle));
+        path.extend(arc(self.outer_radius, self.start_angle, self.sweep_angle).append_iter(0.1));
+        if self.is_full_turn() {
+            path.close_path();
+            if self.inner_radius > 0.0 {
+                path.move_to(self.point(self.inner_radius, end_angle));
+            }
+        } else {
+            path.line_to(self.point(self.inner_radius, end_angle));
+        }
+        if self.inner_radius > 0.0 {
+            path.extend(arc(self.inner_radius, end_angle, -self.sweep_angle).append_iter(0.1));
+        }
+        path.close_path();
+        path
+    }
+}
+
+/// Restricts the primitives between a [`Primitives::PushClip`] and the matching
+/// [`Primitives::PopClip`] to `rect`, e.g. a series to the plot area.
+#[derive(Debug, Clone)]
+pub struct Clip {
+    pub rect: kurbo::Rect,
+}
+
+/// Logical grouping of the primitives between a [`Primitives::PushGroup`] and the matching
+/// [`Primitives::PopGroup`], e.g. all primitives of one axis or one series.
+#[derive(Debug, Clone)]
+pub struct Group {
+    pub id: Option<String>,
+    pub class: &'static str,
+}
+
+impl Group {
+    pub fn new(id: impl Into<String>, class: &'static str) -> Self {
+        Self {
+            id: Some(id.into()),
+            class,
+        }
+    }
+
+    pub fn class(class: &'static str) -> Self {
+        Self { id: None, class }
+    }
+}
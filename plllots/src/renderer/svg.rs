@@ -1,116 +1,664 @@
-use kurbo::Cap;
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Result, Write};
+
+use bon::Builder;
+use kurbo::Point;
+use kurbo::{Affine, BezPath, Cap, Join, PathEl, Stroke};
 use parley::Alignment;
 use peniko::Brush;
 use svg::node::element::path::Data;
-use svg::node::element::{Circle, Path, Rectangle};
+use svg::node::element::{
+    Circle, ClipPath, Definitions, Description, Element, Group, Marker, Path, Polygon, Rectangle,
+    Style, Title, Use,
+};
 use svg::{Document, Node};
 
 use crate::chart::Chart;
-use std::io::Result;
+use crate::component::{Aria, AriaSeries};
+use crate::utils::text::TextMeasurer;
 
-pub struct SvgRenderer;
+#[derive(Debug, Clone, Default, Builder)]
+pub struct SvgRenderer {
+    /// Moves the presentation attributes of all elements into a `<style>` element and
+    /// references the rules through classes, instead of repeating them on every element.
+    #[builder(default)]
+    pub inline_stylesheet: bool,
+    /// Rounds all coordinates and sizes to the given number of decimal places.
+    #[builder(setters(option_fn(vis = "")))]
+    pub precision: Option<usize>,
+    /// Defines repeated symbols once in `<defs>` and places them with `<use>` references
+    /// inside a group carrying their shared style.
+    #[builder(default)]
+    pub reuse_symbols: bool,
+    #[builder(default)]
+    pub text_measurer: TextMeasurer,
+}
 
 impl SvgRenderer {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// A renderer producing small documents for charts with many data points.
+    pub fn compact() -> Self {
+        Self {
+            inline_stylesheet: true,
+            precision: Some(2),
+            reuse_symbols: true,
+            ..Self::default()
+        }
     }
 
     pub fn render(&self, chart: &Chart) -> Document {
-        let background = chart.theme.background.to_rgba8().to_u8_array();
-        let background = format!(
-            "#{:X}{:X}{:X}{:X}",
-            background[0], background[1], background[2], background[3]
-        );
-        let mut doc = Document::new()
+        let mut svg = SvgContext::new(self.root(chart), self);
+        self.draw(chart, &mut svg)
+            .expect("in-memory documents never fail to write");
+        svg.finish()
+    }
+
+    /// Writes the chart to `writer`, emitting every SVG element as soon as it is drawn instead
+    /// of building the whole document in memory first. The chart's primitives are still
+    /// generated up front, only the document is never held in memory. Stops at the first
+    /// write error.
+    pub fn render_to_writer<W: Write>(&self, chart: &Chart, mut writer: W) -> Result<()> {
+        let mut svg = SvgContext::from_writer(&self.root(chart), &mut writer, self)?;
+        self.draw(chart, &mut svg)?;
+        svg.finish_writer()?;
+        writer.flush()
+    }
+
+    pub fn render_to_string(&self, chart: &Chart) -> String {
+        let mut buffer = Vec::new();
+        self.render_to_writer(chart, &mut buffer)
+            .expect("writing to a Vec never fails");
+        String::from_utf8(buffer).expect("SVG output is valid UTF-8")
+    }
+
+    pub fn save<P: AsRef<std::path::Path>>(&self, chart: &Chart, path: P) -> Result<()> {
+        let file = std::fs::File::create(path)?;
+        self.render_to_writer(chart, io::BufWriter::new(file))
+    }
+
+    /// Saves the chart as gzip-compressed SVG, conventionally with an `.svgz` extension.
+    #[cfg(feature = "svgz")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "svgz")))]
+    pub fn save_svgz<P: AsRef<std::path::Path>>(&self, chart: &Chart, path: P) -> Result<()> {
+        use flate2::{Compression, write::GzEncoder};
+
+        let file = std::fs::File::create(path)?;
+        let mut encoder = GzEncoder::new(io::BufWriter::new(file), Compression::best());
+        self.render_to_writer(chart, &mut encoder)?;
+        encoder.finish()?.flush()
+    }
+
+    fn root(&self, chart: &Chart) -> Document {
+        let mut document = Document::new()
             .set("width", chart.size.width)
             .set("height", chart.size.height)
-            .set("viewBox", (0, 0, chart.size.width, chart.size.height))
-            .add(
-                Rectangle::new()
-                    .set("width", chart.size.width)
-                    .set("height", chart.size.height)
-                    .set("x", 0)
-                    .set("y", 0)
-                    .set("fill", background),
-            );
+            .set("viewBox", (0, 0, chart.size.width, chart.size.height));
+        if let Some(aria) = &chart.aria {
+            // `img` would hide the data tables from assistive technology
+            let role = if aria.data_table { "figure" } else { "img" };
+            document.assign("role", role);
+            document.assign("aria-label", aria_label(chart, aria));
+        }
+        document
+    }
+
+    fn draw(&self, chart: &Chart, svg: &mut SvgContext) -> Result<()> {
+        if let Some(aria) = &chart.aria {
+            self.draw_aria(chart, aria, svg);
+            svg.check()?;
+        }
 
-        let primitives = chart.generate_primitives();
+        let background = brush_to_svg(&Brush::Solid(chart.theme.background));
+        svg.append(
+            Rectangle::new()
+                .set("class", "background")
+                .set("width", chart.size.width)
+                .set("height", chart.size.height)
+                .set("x", 0)
+                .set("y", 0)
+                .set("fill", background),
+        );
+
+        let primitives = chart.generate_primitives(&self.text_measurer);
         for primitive in primitives {
-            primitive.append_svg(&mut doc);
+            primitive.append_svg(svg);
+            svg.check()?;
         }
-        doc
+        Ok(())
     }
 
-    pub fn save<P: AsRef<std::path::Path>>(&self, chart: &Chart, path: P) -> Result<()> {
-        let doc = self.render(chart);
-        svg::save(path, &doc)
+    fn draw_aria(&self, chart: &Chart, aria: &Aria, svg: &mut SvgContext) {
+        svg.append(Title::new(aria_label(chart, aria)));
+
+        let series = chart.aria_series();
+        let mut description = Vec::new();
+        description.extend(aria.description.clone());
+        if aria.series_summary {
+            description.extend(series.iter().map(|s| s.summary.clone()));
+        }
+        if !description.is_empty() {
+            svg.append(Description::new().add(svg::node::Text::new(description.join(" "))));
+        }
+
+        if aria.data_table {
+            for AriaSeries { name, columns, .. } in series {
+                let mut table = Group::new()
+                    .set("class", "data-table")
+                    .set("role", "table")
+                    .set("aria-label", name)
+                    .set("opacity", 0)
+                    .set("pointer-events", "none");
+                let mut header = Group::new().set("role", "row");
+                for (title, _) in &columns {
+                    header.append(
+                        svg::node::element::Text::new(title.as_str()).set("role", "columnheader"),
+                    );
+                }
+                table.append(header);
+                let rows = columns.iter().map(|(_, c)| c.len()).max().unwrap_or(0);
+                for row_index in 0..rows {
+                    let mut row = Group::new().set("role", "row");
+                    for (_, column) in &columns {
+                        let value = column.get(row_index).map_or("", String::as_str);
+                        row.append(svg::node::element::Text::new(value).set("role", "cell"));
+                    }
+                    table.append(row);
+                }
+                svg.append(table);
+            }
+        }
     }
 }
 
-impl Default for SvgRenderer {
-    fn default() -> Self {
-        Self::new()
+fn aria_label(chart: &Chart, aria: &Aria) -> String {
+    match &aria.label {
+        Some(label) => label.clone(),
+        None => format!("Chart with {} series", chart.aria_series().len()),
     }
 }
 
 pub trait AppendSvg {
-    fn append_svg(&self, doc: &mut Document);
+    fn append_svg(&self, svg: &mut SvgContext);
 }
 
-impl AppendSvg for crate::primitives::Line<'_> {
-    fn append_svg(&self, doc: &mut svg::Document) {
-        let stroke_color = match &self.stroke_color {
-            Brush::Solid(alpha_color) => {
-                let colors = alpha_color.to_rgba8().to_u8_array();
-                format!(
-                    "#{:X}{:X}{:X}{:X}",
-                    colors[0], colors[1], colors[2], colors[3]
-                )
+/// Attributes that are moved into the stylesheet when [`SvgRenderer::inline_stylesheet`] is set.
+const PRESENTATION_ATTRIBUTES: [&str; 14] = [
+    "dominant-baseline",
+    "fill",
+    "fill-opacity",
+    "marker-end",
+    "marker-start",
+    "stroke",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "stroke-width",
+    "style",
+    "text-anchor",
+];
+
+/// The document under construction while rendering a chart to SVG.
+///
+/// Elements are appended to the innermost open group, definitions such as markers are
+/// collected once into a shared `<defs>` element.
+pub struct SvgContext<'w> {
+    output: Output<'w>,
+    definition_ids: HashSet<String>,
+    stylesheet: Option<Stylesheet>,
+    precision: Option<usize>,
+    reuse_symbols: bool,
+}
+
+enum Output<'w> {
+    Document {
+        document: Document,
+        groups: Vec<Group>,
+        definitions: Definitions,
+    },
+    /// Elements are written as soon as they are appended. The open tag of a group is
+    /// deferred until its first child, so that empty groups are dropped like in the
+    /// in-memory document.
+    Writer {
+        writer: &'w mut dyn Write,
+        groups: Vec<(Group, bool)>,
+        error: Option<io::Error>,
+    },
+}
+
+#[derive(Default)]
+struct Stylesheet {
+    classes: HashMap<String, String>,
+    rules: Vec<(String, String)>,
+}
+
+impl<'w> SvgContext<'w> {
+    pub(crate) fn new(document: Document, renderer: &SvgRenderer) -> Self {
+        Self::with_output(
+            Output::Document {
+                document,
+                groups: vec![Group::new()],
+                definitions: Definitions::new(),
+            },
+            renderer,
+        )
+    }
+
+    pub(crate) fn from_writer(
+        document: &Document,
+        writer: &'w mut dyn Write,
+        renderer: &SvgRenderer,
+    ) -> Result<Self> {
+        writeln!(writer, "{}", open_tag(document))?;
+        Ok(Self::with_output(
+            Output::Writer {
+                writer,
+                groups: Vec::new(),
+                error: None,
+            },
+            renderer,
+        ))
+    }
+
+    fn with_output(output: Output<'w>, renderer: &SvgRenderer) -> Self {
+        Self {
+            output,
+            definition_ids: HashSet::new(),
+            stylesheet: renderer.inline_stylesheet.then(Stylesheet::default),
+            precision: renderer.precision,
+            reuse_symbols: renderer.reuse_symbols,
+        }
+    }
+
+    /// Rounds `value` to the configured [`SvgRenderer::precision`].
+    pub fn number(&self, value: f64) -> f64 {
+        match self.precision {
+            Some(precision) => {
+                let factor = 10f64.powi(precision as i32);
+                (value * factor).round() / factor
+            }
+            None => value,
+        }
+    }
+
+    pub fn point(&self, point: Point) -> (f64, f64) {
+        (self.number(point.x), self.number(point.y))
+    }
+
+    pub fn append<N: Node>(&mut self, mut node: N) {
+        if let Some(stylesheet) = &mut self.stylesheet {
+            stylesheet.apply(&mut node);
+        }
+        self.append_raw(node);
+    }
+
+    fn append_raw<N: Node>(&mut self, node: N) {
+        match &mut self.output {
+            Output::Document { groups, .. } => {
+                let parent = groups.last_mut().expect("root group is never popped");
+                parent.append(node);
             }
-            Brush::Gradient(_gradient) => todo!(),
-            Brush::Image(_image) => todo!(),
+            Output::Writer { error: Some(_), .. } => {}
+            Output::Writer {
+                writer,
+                groups,
+                error,
+            } => {
+                let mut result = Ok(());
+                for (group, opened) in groups.iter_mut().filter(|(_, opened)| !*opened) {
+                    *opened = true;
+                    result = result.and_then(|_| writeln!(writer, "{}", open_tag(group)));
+                }
+                result = result.and_then(|_| writeln!(writer, "{node}"));
+                if let Err(e) = result {
+                    error.get_or_insert(e);
+                }
+            }
+        }
+    }
+
+    pub fn push_group(&mut self, group: &crate::primitives::Group) {
+        let mut element = Group::new().set("class", group.class);
+        if let Some(id) = &group.id {
+            element.assign("id", id.as_str());
+        }
+        self.push_element(element);
+    }
+
+    /// Opens a group clipped to the rectangle of `clip`, closed by [`Self::pop_group`].
+    /// Clips with the same rectangle share one `<clipPath>` definition.
+    pub fn push_clip(&mut self, clip: &crate::primitives::Clip) {
+        let (x, y) = self.point(clip.rect.origin());
+        let (width, height) = (
+            self.number(clip.rect.width()),
+            self.number(clip.rect.height()),
+        );
+        let id = format!("clip-{x}-{y}-{width}-{height}").replace('.', "_");
+        self.define(
+            &id,
+            ClipPath::new().set("id", id.as_str()).add(
+                Rectangle::new()
+                    .set("x", x)
+                    .set("y", y)
+                    .set("width", width)
+                    .set("height", height),
+            ),
+        );
+        self.push_element(Group::new().set("clip-path", format!("url(#{id})")));
+    }
+
+    fn push_element(&mut self, element: Group) {
+        match &mut self.output {
+            Output::Document { groups, .. } => groups.push(element),
+            Output::Writer { groups, .. } => groups.push((element, false)),
+        }
+    }
+
+    /// Closes the innermost group, dropping it if nothing was drawn into it.
+    pub fn pop_group(&mut self) {
+        match &mut self.output {
+            Output::Document { groups, .. } => {
+                if groups.len() > 1 {
+                    let group = groups.pop().expect("checked above");
+                    if Node::get_children(&group).is_some_and(|children| !children.is_empty()) {
+                        self.append_raw(group);
+                    }
+                }
+            }
+            Output::Writer {
+                writer,
+                groups,
+                error,
+            } => {
+                if let Some((_, true)) = groups.pop()
+                    && error.is_none()
+                    && let Err(e) = writeln!(writer, "</g>")
+                {
+                    error.get_or_insert(e);
+                }
+            }
+        }
+    }
+
+    /// Returns the first write error of a streaming document, so that rendering stops there.
+    fn check(&mut self) -> Result<()> {
+        match &mut self.output {
+            Output::Writer { error, .. } => error.take().map_or(Ok(()), Err),
+            Output::Document { .. } => Ok(()),
+        }
+    }
+
+    /// Adds `node` to the shared `<defs>` unless a definition with the same `id` exists.
+    pub fn define<N: Node>(&mut self, id: &str, node: N) {
+        if self.definition_ids.insert(id.to_string()) {
+            match &mut self.output {
+                Output::Document { definitions, .. } => definitions.append(node),
+                Output::Writer { .. } => self.append_raw(Definitions::new().add(node)),
+            }
+        }
+    }
+
+    pub(crate) fn finish(self) -> Document {
+        let Output::Document {
+            mut document,
+            mut groups,
+            definitions,
+        } = self.output
+        else {
+            unreachable!("finish is only called on in-memory documents")
         };
-        let cap = match (self.stroke.start_cap, self.stroke.end_cap) {
-            (Cap::Butt, Cap::Butt) => "butt",
-            // (Cap::Butt, Cap::Square) => todo!(),
-            // (Cap::Butt, Cap::Round) => todo!(),
-            // (Cap::Square, Cap::Butt) => todo!(),
-            (Cap::Square, Cap::Square) => "square",
-            // (Cap::Square, Cap::Round) => todo!(),
-            // (Cap::Round, Cap::Butt) => todo!(),
-            // (Cap::Round, Cap::Square) => todo!(),
-            (Cap::Round, Cap::Round) => "round",
-            _ => todo!(),
+        while groups.len() > 1 {
+            let group = groups.pop().expect("checked above");
+            if Node::get_children(&group).is_some_and(|children| !children.is_empty()) {
+                groups.last_mut().expect("checked above").append(group);
+            }
+        }
+        let mut root: Element = groups.pop().expect("root group is never popped").into();
+        let mut children = std::mem::take(root.get_children_mut());
+        // The accessible name and description have to be the first children of the `<svg>`.
+        let aria = children
+            .iter()
+            .take_while(|child| matches!(child.get_name(), "title" | "desc"))
+            .count();
+        let mut rest = children.split_off(aria);
+
+        let document_element: &mut Element = &mut document;
+        document_element.get_children_mut().append(&mut children);
+        if let Some(stylesheet) = self.stylesheet.filter(|s| !s.rules.is_empty()) {
+            document.append(Style::new(stylesheet.to_css()));
+        }
+        if !self.definition_ids.is_empty() {
+            document.append(definitions);
+        }
+        let document_element: &mut Element = &mut document;
+        document_element.get_children_mut().append(&mut rest);
+        document
+    }
+
+    /// Closes all open groups and the document. Styles are emitted last, which is valid
+    /// since a `<style>` element applies to the whole document regardless of its position.
+    pub(crate) fn finish_writer(mut self) -> Result<()> {
+        while let Output::Writer { groups, .. } = &self.output {
+            if groups.is_empty() {
+                break;
+            }
+            self.pop_group();
+        }
+        let stylesheet = self.stylesheet.take();
+        let Output::Writer { writer, error, .. } = self.output else {
+            unreachable!("finish_writer is only called on streaming documents")
         };
+        if let Some(e) = error {
+            return Err(e);
+        }
+        if let Some(stylesheet) = stylesheet.filter(|s| !s.rules.is_empty()) {
+            writeln!(writer, "{}", Style::new(stylesheet.to_css()))?;
+        }
+        writeln!(writer, "</svg>")
+    }
+}
 
-        doc.append(
-            Path::new()
-                .set("stroke", stroke_color)
-                .set("stroke-width", self.stroke.width)
-                .set("stroke-linecap", cap)
-                .set(
-                    "d",
-                    Data::new()
-                        .move_to((self.coords.0.x, self.coords.0.y))
-                        .line_to((self.coords.1.x, self.coords.1.y)),
-                ),
-        );
+/// Formats the opening tag of `element`, ignoring its children.
+fn open_tag(element: &Element) -> String {
+    let mut attributes = element.get_attributes().iter().collect::<Vec<_>>();
+    attributes.sort_by_key(|(name, _)| name.as_str());
+    let mut tag = format!("<{}", element.get_name());
+    for (name, value) in attributes {
+        tag.push_str(&format!(r#" {name}="{}""#, escape(value)));
     }
+    tag.push('>');
+    tag
 }
 
-impl AppendSvg for crate::primitives::Text<'_> {
-    fn append_svg(&self, doc: &mut svg::Document) {
-        let fill_color = match &self.fill_color {
-            Brush::Solid(alpha_color) => {
-                let colors = alpha_color.to_rgba8().to_u8_array();
-                format!(
-                    "#{:X}{:X}{:X}{:X}",
-                    colors[0], colors[1], colors[2], colors[3]
-                )
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+impl Stylesheet {
+    fn apply<N: Node>(&mut self, node: &mut N) {
+        let Some(attributes) = node.get_attributes_mut() else {
+            return;
+        };
+        let mut declarations = Vec::new();
+        for name in PRESENTATION_ATTRIBUTES {
+            if let Some(value) = attributes.remove(name) {
+                match name {
+                    "style" => declarations.push(value.to_string()),
+                    _ => declarations.push(format!("{name}:{value}")),
+                }
             }
-            Brush::Gradient(_gradient) => todo!(),
-            Brush::Image(_image) => todo!(),
+        }
+        if declarations.is_empty() {
+            return;
+        }
+
+        let declarations = declarations.join(";");
+        let class = match self.classes.get(&declarations) {
+            Some(class) => class.clone(),
+            None => {
+                let class = format!("s{}", self.rules.len());
+                self.classes.insert(declarations.clone(), class.clone());
+                self.rules.push((class.clone(), declarations));
+                class
+            }
+        };
+        let class = match attributes.get("class") {
+            Some(existing) => format!("{existing} {class}"),
+            None => class,
         };
+        attributes.insert("class".to_string(), class.into());
+    }
+
+    fn to_css(&self) -> String {
+        self.rules
+            .iter()
+            .map(|(class, declarations)| format!(".{class}{{{declarations}}}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+pub(crate) fn brush_to_svg(brush: &Brush) -> String {
+    match brush {
+        Brush::Solid(alpha_color) => {
+            let colors = alpha_color.to_rgba8().to_u8_array();
+            format!(
+                "#{:02X}{:02X}{:02X}{:02X}",
+                colors[0], colors[1], colors[2], colors[3]
+            )
+        }
+        Brush::Gradient(_gradient) => todo!(),
+        Brush::Image(_image) => todo!(),
+    }
+}
+
+/// Maps every property of a [`Stroke`] onto the SVG presentation attributes of `node`.
+///
+/// SVG only knows a single `stroke-linecap`, so open paths with differing start and end caps
+/// are drawn with butt caps and the remaining caps are emulated with markers. Markers only
+/// cap the ends of the path while Vello caps every dash, so dashed strokes fall back to the
+/// end cap on both sides.
+fn set_stroke<N: Node>(
+    mut node: N,
+    svg: &mut SvgContext,
+    stroke: &Stroke,
+    stroke_color: &str,
+    open: bool,
+) -> N {
+    node.assign("stroke", stroke_color);
+    node.assign("stroke-width", stroke.width);
+
+    let join = match stroke.join {
+        Join::Bevel => "bevel",
+        Join::Miter => "miter",
+        Join::Round => "round",
+    };
+    node.assign("stroke-linejoin", join);
+    if stroke.join == Join::Miter {
+        node.assign("stroke-miterlimit", stroke.miter_limit);
+    }
+
+    if !stroke.dash_pattern.is_empty() {
+        node.assign(
+            "stroke-dasharray",
+            stroke
+                .dash_pattern
+                .iter()
+                .map(f64::to_string)
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        if stroke.dash_offset != 0.0 {
+            node.assign("stroke-dashoffset", stroke.dash_offset);
+        }
+    }
+
+    if stroke.start_cap == stroke.end_cap || !stroke.dash_pattern.is_empty() {
+        node.assign("stroke-linecap", cap_name(stroke.end_cap));
+    } else if open {
+        node.assign("stroke-linecap", cap_name(Cap::Butt));
+        if stroke.start_cap != Cap::Butt {
+            node.assign(
+                "marker-start",
+                cap_marker(svg, stroke.start_cap, stroke_color),
+            );
+        }
+        if stroke.end_cap != Cap::Butt {
+            node.assign("marker-end", cap_marker(svg, stroke.end_cap, stroke_color));
+        }
+    }
+    node
+}
+
+fn cap_name(cap: Cap) -> &'static str {
+    match cap {
+        Cap::Butt => "butt",
+        Cap::Square => "square",
+        Cap::Round => "round",
+    }
+}
+
+/// Returns a reference to a marker drawing `cap` in `color`, adding it to the document's
+/// `<defs>` the first time it is requested.
+///
+/// The marker is measured in stroke widths and oriented along the path, so a single
+/// definition serves every stroke width as well as both ends of a path.
+fn cap_marker(svg: &mut SvgContext, cap: Cap, color: &str) -> String {
+    let id = format!("cap-{}-{}", cap_name(cap), color.trim_start_matches('#'));
+    let shape = match cap {
+        Cap::Butt => Data::new(),
+        Cap::Square => Data::new()
+            .move_to((0.0, -0.5))
+            .line_to((0.5, -0.5))
+            .line_to((0.5, 0.5))
+            .line_to((0.0, 0.5))
+            .close(),
+        Cap::Round => Data::new()
+            .move_to((0.0, -0.5))
+            .elliptical_arc_to((0.5, 0.5, 0, 0, 1, 0.0, 0.5))
+            .close(),
+    };
+    svg.define(
+        &id,
+        Marker::new()
+            .set("id", id.as_str())
+            .set("viewBox", (0.0, -0.5, 0.5, 1.0))
+            .set("markerWidth", 0.5)
+            .set("markerHeight", 1.0)
+            .set("refX", 0)
+            .set("refY", 0)
+            .set("orient", "auto-start-reverse")
+            .add(Path::new().set("fill", color).set("d", shape)),
+    );
+    format!("url(#{id})")
+}
+
+impl AppendSvg for crate::primitives::Line<'_> {
+    fn append_svg(&self, svg: &mut SvgContext) {
+        let stroke_color = brush_to_svg(self.stroke_color);
+        let path = Path::new().set(
+            "d",
+            Data::new()
+                .move_to(svg.point(self.coords.0))
+                .line_to(svg.point(self.coords.1)),
+        );
+        let path = set_stroke(path, svg, self.stroke, &stroke_color, true);
+        svg.append(path);
+    }
+}
+
+impl AppendSvg for crate::primitives::Text<'_> {
+    fn append_svg(&self, svg: &mut SvgContext) {
+        let fill_color = brush_to_svg(self.fill_color);
 
         let text_anchor = match self.text_anchor {
             Alignment::Start => "start",
@@ -122,6 +670,7 @@ impl AppendSvg for crate::primitives::Text<'_> {
         };
 
         let style = format!("font-size:{}px;font-family:sans-serif", self.font_size);
+        let (x, y) = svg.point(self.coord);
         let transform = match self.rotation {
             Some(r) => format!(
                 "matrix({}, {}, {}, {}, {}, {})",
@@ -129,13 +678,13 @@ impl AppendSvg for crate::primitives::Text<'_> {
                 r.sin(),
                 -r.sin(),
                 r.cos(),
-                self.coord.x,
-                self.coord.y
+                x,
+                y
             ),
-            None => format!("translate({} {})", self.coord.x, self.coord.y),
+            None => format!("translate({} {})", x, y),
         };
 
-        doc.append(
+        svg.append(
             svg::node::element::Text::new(&self.text)
                 .set("dominant-baseline", "central")
                 .set("text-anchor", text_anchor)
@@ -147,112 +696,749 @@ impl AppendSvg for crate::primitives::Text<'_> {
 }
 
 impl AppendSvg for crate::primitives::Path<'_> {
-    fn append_svg(&self, doc: &mut svg::Document) {
-        let stroke_color = match &self.stroke_color {
-            Brush::Solid(alpha_color) => {
-                let colors = alpha_color.to_rgba8().to_u8_array();
-                format!(
-                    "#{:X}{:X}{:X}{:X}",
-                    colors[0], colors[1], colors[2], colors[3]
-                )
-            }
-            Brush::Gradient(_gradient) => todo!(),
-            Brush::Image(_image) => todo!(),
-        };
+    fn append_svg(&self, svg: &mut SvgContext) {
+        let stroke_color = brush_to_svg(self.stroke_color);
 
         let mut path = Data::new();
         for (index, point) in self.coords.iter().enumerate() {
             if index == 0 {
-                path = path.move_to((point.x, point.y));
+                path = path.move_to(svg.point(*point));
             } else {
-                path = path.line_to((point.x, point.y));
+                path = path.line_to(svg.point(*point));
             }
         }
 
-        doc.append(
-            Path::new()
-                .set("stroke", stroke_color)
-                .set("stroke-width", self.stroke.width)
-                .set("fill", "transparent")
-                .set("d", path),
-        );
+        let path = Path::new().set("fill", "transparent").set("d", path);
+        let path = set_stroke(path, svg, self.stroke, &stroke_color, true);
+        svg.append(path);
     }
 }
 
 impl AppendSvg for crate::primitives::Circle<'_> {
-    fn append_svg(&self, doc: &mut Document) {
-        let stroke_color = match &self.stroke_color {
-            Brush::Solid(alpha_color) => {
-                let colors = alpha_color.to_rgba8().to_u8_array();
-                format!(
-                    "#{:X}{:X}{:X}{:X}",
-                    colors[0], colors[1], colors[2], colors[3]
-                )
+    fn append_svg(&self, svg: &mut SvgContext) {
+        let stroke_color = brush_to_svg(self.stroke_color);
+
+        let fill_color = brush_to_svg(self.fill_color);
+
+        let circle = Circle::new()
+            .set("r", svg.number(self.radius))
+            .set("cx", svg.number(self.coord.x))
+            .set("cy", svg.number(self.coord.y))
+            .set("fill", fill_color);
+        let circle = set_stroke(circle, svg, self.stroke, &stroke_color, false);
+        svg.append(circle);
+    }
+}
+
+impl AppendSvg for crate::primitives::Rect<'_> {
+    fn append_svg(&self, svg: &mut SvgContext) {
+        let stroke_color = brush_to_svg(self.stroke_color);
+
+        let fill_color = brush_to_svg(self.fill_color);
+
+        let (x, y) = svg.point(self.rect.origin());
+        let rect = Rectangle::new()
+            .set("x", x)
+            .set("y", y)
+            .set("width", svg.number(self.rect.width()))
+            .set("height", svg.number(self.rect.height()))
+            .set("fill", fill_color);
+        let rect = set_stroke(rect, svg, self.stroke, &stroke_color, false);
+        svg.append(rect);
+    }
+}
+
+impl AppendSvg for crate::primitives::Polygon<'_> {
+    fn append_svg(&self, svg: &mut SvgContext) {
+        let stroke_color = brush_to_svg(self.stroke_color);
+
+        let fill_color = brush_to_svg(self.fill_color);
+
+        let points = self
+            .coords
+            .iter()
+            .map(|point| {
+                let (x, y) = svg.point(*point);
+                format!("{x},{y}")
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut polygon = Polygon::new().set("points", points).set("fill", fill_color);
+        if self.fill_opacity < 1.0 {
+            polygon.assign("fill-opacity", self.fill_opacity);
+        }
+        let polygon = set_stroke(polygon, svg, self.stroke, &stroke_color, false);
+        svg.append(polygon);
+    }
+}
+
+impl AppendSvg for crate::primitives::MultiCircle<'_> {
+    fn append_svg(&self, svg: &mut SvgContext) {
+        let stroke_color = brush_to_svg(self.stroke_color);
+
+        let fill_color = brush_to_svg(self.fill_color);
+
+        let radius = svg.number(self.radius);
+        if svg.reuse_symbols {
+            let id = format!("circle-{radius}").replace('.', "_");
+            svg.define(&id, Circle::new().set("id", id.as_str()).set("r", radius));
+
+            let href = format!("#{id}");
+            let mut group = Group::new().set("fill", fill_color);
+            for coord in &self.coords {
+                let (x, y) = svg.point(*coord);
+                group.append(
+                    Use::new()
+                        .set("href", href.as_str())
+                        .set("x", x)
+                        .set("y", y),
+                );
             }
-            Brush::Gradient(_gradient) => todo!(),
-            Brush::Image(_image) => todo!(),
-        };
+            let group = set_stroke(group, svg, self.stroke, &stroke_color, false);
+            svg.append(group);
+            return;
+        }
+
+        for coord in &self.coords {
+            let (x, y) = svg.point(*coord);
+            let circle = Circle::new()
+                .set("r", radius)
+                .set("cx", x)
+                .set("cy", y)
+                .set("fill", fill_color.clone());
+            let circle = set_stroke(circle, svg, self.stroke, &stroke_color, false);
+            svg.append(circle);
+        }
+    }
+}
 
-        let fill_color = match &self.fill_color {
-            Brush::Solid(alpha_color) => {
-                let colors = alpha_color.to_rgba8().to_u8_array();
-                format!(
-                    "#{:X}{:X}{:X}{:X}",
-                    colors[0], colors[1], colors[2], colors[3]
-                )
+impl AppendSvg for crate::primitives::Arc<'_> {
+    fn append_svg(&self, svg: &mut SvgContext) {
+        let stroke_color = brush_to_svg(self.stroke_color);
+
+        let fill_color = brush_to_svg(self.fill_color);
+
+        // Arcs of at most half a turn keep the large arc flag unset and a full ring drawable.
+        let segments = (self.sweep_angle.abs() / std::f64::consts::PI)
+            .ceil()
+            .max(1.0);
+        let step = self.sweep_angle / segments;
+        let sweep_flag = if step > 0.0 { 1 } else { 0 };
+        let arcs = |mut data: Data, radius: f64, start: f64, step: f64, sweep_flag: u8| {
+            let r = svg.number(radius);
+            for segment in 1..=segments as usize {
+                let (x, y) = svg.point(self.point(radius, start + step * segment as f64));
+                data = data.elliptical_arc_to((r, r, 0, 0, sweep_flag, x, y));
             }
-            Brush::Gradient(_gradient) => todo!(),
-            Brush::Image(_image) => todo!(),
+            data
         };
 
-        doc.append(
-            Circle::new()
-                .set("r", self.radius)
-                .set("cx", self.coord.x)
-                .set("cy", self.coord.y)
-                .set("fill", fill_color)
-                .set("stroke", stroke_color)
-                .set("stroke-width", self.stroke.width),
-        )
+        let end_angle = self.start_angle + self.sweep_angle;
+        let data = Data::new().move_to(svg.point(self.point(self.outer_radius, self.start_angle)));
+        let data = arcs(data, self.outer_radius, self.start_angle, step, sweep_flag);
+        let inner_start = svg.point(self.point(self.inner_radius, end_angle));
+        let data = match self.is_full_turn() {
+            // The inner circle runs the other way and cuts a hole with the nonzero fill rule.
+            true if self.inner_radius > 0.0 => data.close().move_to(inner_start),
+            true => data,
+            false => data.line_to(inner_start),
+        };
+        let data = match self.inner_radius > 0.0 {
+            true => arcs(data, self.inner_radius, end_angle, -step, 1 - sweep_flag),
+            false => data,
+        };
+
+        let path = Path::new().set("d", data.close()).set("fill", fill_color);
+        let path = set_stroke(path, svg, self.stroke, &stroke_color, false);
+        svg.append(path);
     }
 }
 
-impl AppendSvg for crate::primitives::MultiCircle<'_> {
-    fn append_svg(&self, doc: &mut Document) {
-        let stroke_color = match &self.stroke_color {
-            Brush::Solid(alpha_color) => {
-                let colors = alpha_color.to_rgba8().to_u8_array();
-                format!(
-                    "#{:X}{:X}{:X}{:X}",
-                    colors[0], colors[1], colors[2], colors[3]
-                )
+impl AppendSvg for crate::primitives::MultiSymbol<'_> {
+    fn append_svg(&self, svg: &mut SvgContext) {
+        let stroke_color = brush_to_svg(self.stroke_color);
+
+        let fill_color = brush_to_svg(self.fill_color);
+
+        let shape = self.symbol.path(self.radius, self.rotation);
+        if svg.reuse_symbols {
+            let mut id = format!("symbol-{}-{}", self.symbol.name(), svg.number(self.radius));
+            if let Some(rotation) = self.rotation {
+                id.push_str(&format!("-{}", svg.number(rotation.to_degrees())));
             }
-            Brush::Gradient(_gradient) => todo!(),
-            Brush::Image(_image) => todo!(),
-        };
+            let id = id.replace('.', "_");
+            let data = path_data(&shape, svg);
+            svg.define(&id, Path::new().set("id", id.as_str()).set("d", data));
 
-        let fill_color = match &self.fill_color {
-            Brush::Solid(alpha_color) => {
-                let colors = alpha_color.to_rgba8().to_u8_array();
-                format!(
-                    "#{:X}{:X}{:X}{:X}",
-                    colors[0], colors[1], colors[2], colors[3]
-                )
+            let href = format!("#{id}");
+            let mut group = Group::new().set("fill", fill_color);
+            for coord in &self.coords {
+                let (x, y) = svg.point(*coord);
+                group.append(
+                    Use::new()
+                        .set("href", href.as_str())
+                        .set("x", x)
+                        .set("y", y),
+                );
             }
-            Brush::Gradient(_gradient) => todo!(),
-            Brush::Image(_image) => todo!(),
-        };
+            let group = set_stroke(group, svg, self.stroke, &stroke_color, false);
+            svg.append(group);
+            return;
+        }
 
         for coord in &self.coords {
-            doc.append(
-                Circle::new()
-                    .set("r", self.radius)
-                    .set("cx", coord.x)
-                    .set("cy", coord.y)
-                    .set("fill", fill_color.clone())
-                    .set("stroke", stroke_color.clone())
-                    .set("stroke-width", self.stroke.width),
-            );
+            let shape = Affine::translate(coord.to_vec2()) * &shape;
+            let path = Path::new()
+                .set("d", path_data(&shape, svg))
+                .set("fill", fill_color.clone());
+            let path = set_stroke(path, svg, self.stroke, &stroke_color, false);
+            svg.append(path);
+        }
+    }
+}
+
+/// Path data of `path` with the coordinates rounded to the precision of `svg`.
+fn path_data(path: &BezPath, svg: &SvgContext) -> Data {
+    path.elements()
+        .iter()
+        .fold(Data::new(), |data, element| match *element {
+            PathEl::MoveTo(p) => data.move_to(svg.point(p)),
+            PathEl::LineTo(p) => data.line_to(svg.point(p)),
+            PathEl::QuadTo(p1, p2) => data.quadratic_curve_to((svg.point(p1), svg.point(p2))),
+            PathEl::CurveTo(p1, p2, p3) => {
+                data.cubic_curve_to((svg.point(p1), svg.point(p2), svg.point(p3)))
+            }
+            PathEl::ClosePath => data.close(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kurbo::Point;
+    use peniko::Color;
+
+    #[test]
+    fn stroke_attributes() {
+        let stroke = Stroke::new(2.0)
+            .with_join(Join::Round)
+            .with_dashes(1.5, [4.0, 2.0])
+            .with_start_cap(Cap::Butt)
+            .with_end_cap(Cap::Round);
+        let color = Brush::Solid(Color::from_rgba8(0x0e, 0x70, 0x79, 0xff));
+        let line = crate::primitives::Line {
+            stroke: &stroke,
+            stroke_color: &color,
+            coords: (Point::new(0.0, 0.0), Point::new(10.0, 0.0)),
+        };
+
+        let mut svg = SvgContext::new(Document::new(), &SvgRenderer::new());
+        line.append_svg(&mut svg);
+        line.append_svg(&mut svg);
+        let output = svg.finish().to_string();
+
+        assert!(output.contains(r##"stroke="#0E7079FF""##));
+        assert!(output.contains(r#"stroke-dasharray="4,2""#));
+        assert!(output.contains(r#"stroke-dashoffset="1.5""#));
+        assert!(output.contains(r#"stroke-linejoin="round""#));
+        assert!(output.contains(r#"stroke-linecap="round""#));
+        assert!(!output.contains("<marker"));
+
+        let solid = Stroke::new(2.0)
+            .with_start_cap(Cap::Butt)
+            .with_end_cap(Cap::Round);
+        let line = crate::primitives::Line {
+            stroke: &solid,
+            ..line
+        };
+        let mut svg = SvgContext::new(Document::new(), &SvgRenderer::new());
+        line.append_svg(&mut svg);
+        line.append_svg(&mut svg);
+        let output = svg.finish().to_string();
+
+        assert!(output.contains(r#"stroke-linecap="butt""#));
+        assert!(output.contains(r##"marker-end="url(#cap-round-0E7079FF)""##));
+        assert!(!output.contains("marker-start"));
+        assert_eq!(output.matches("<marker").count(), 1);
+    }
+
+    fn chart() -> Chart {
+        use crate::component::{CategoryAxis, ValueAxis};
+        use crate::coordinate_system::{Cartesian, CoordinateSystem};
+        use crate::series::Line;
+
+        Chart::builder()
+            .size(400.0, 300.0)
+            .coordinate_system(CoordinateSystem::Cartesian(
+                Cartesian::builder()
+                    .x_axis(
+                        CategoryAxis::builder()
+                            .data(bon::vec!["Mon", "Tue", "Wed"])
+                            .build(),
+                    )
+                    .y_axis(ValueAxis::default())
+                    .add_series(Line::builder().data(vec![150.0, 230.0, 224.0]).build())
+                    .build(),
+            ))
+            .build()
+    }
+
+    fn cartesian(chart: &mut Chart) -> &mut crate::coordinate_system::Cartesian {
+        match &mut chart.coordinate_system {
+            Some(crate::coordinate_system::CoordinateSystem::Cartesian(cartesian)) => cartesian,
+            _ => unreachable!("the test charts are cartesian"),
+        }
+    }
+
+    #[test]
+    fn groups() {
+        let output = SvgRenderer::new().render(&chart()).to_string();
+
+        assert!(output.contains(r#"<g class="series" id="series-0">"#));
+        assert!(output.contains(r#"<g class="axis x-axis" id="x-axis-0">"#));
+        assert!(output.contains(r#"<g class="axis y-axis" id="y-axis-0">"#));
+        assert!(output.contains(r#"<g class="split-line">"#));
+        assert!(!output.contains("<style>"));
+    }
+
+    #[test]
+    fn clip() {
+        use crate::series::Series;
+
+        let output = SvgRenderer::compact().render_to_string(&chart());
+        assert!(output.contains(r#"<clipPath id="clip-40-60-320-180">"#));
+        assert!(output.contains(r#"<g clip-path="url(#clip-40-60-320-180)">"#));
+
+        let mut chart = chart();
+        let cartesian = cartesian(&mut chart);
+        let Series::Line(line) = &mut cartesian.series[0] else {
+            unreachable!()
+        };
+        line.label = Some(crate::component::DataLabel::builder().build());
+        let output = SvgRenderer::new().render_to_string(&chart);
+        let clip = &output[output.find("<g clip-path").unwrap()..];
+        let clip = &clip[..clip.find("</g>").unwrap()];
+        assert!(!clip.contains("<text"));
+        assert!(output.contains("\n230\n"));
+
+        let cartesian = self::cartesian(&mut chart);
+        let Series::Line(line) = &mut cartesian.series[0] else {
+            unreachable!()
+        };
+        line.clip = false;
+        assert!(
+            !SvgRenderer::new()
+                .render(&chart)
+                .to_string()
+                .contains("clip")
+        );
+    }
+
+    #[test]
+    fn inline_stylesheet() {
+        let output = SvgRenderer::builder()
+            .inline_stylesheet(true)
+            .build()
+            .render(&chart())
+            .to_string();
+
+        assert!(output.contains("<style>"));
+        assert!(output.contains(r#"<g class="series" id="series-0">"#));
+        assert!(!output.contains("stroke-width="));
+        assert!(!output.contains("fill="));
+    }
+
+    #[test]
+    fn compact() {
+        use crate::component::ValueAxis;
+        use crate::coordinate_system::{Cartesian, CoordinateSystem};
+        use crate::series::{Scatter, ScatterData};
+
+        let chart = Chart::builder()
+            .size(333.0, 333.0)
+            .coordinate_system(CoordinateSystem::Cartesian(
+                Cartesian::builder()
+                    .x_axis(ValueAxis::default())
+                    .y_axis(ValueAxis::default())
+                    .add_series(
+                        Scatter::builder()
+                            .data(
+                                ScatterData::builder()
+                                    .data(vec![vec![1.0, 2.0, 3.0], vec![3.0, 1.0, 2.0]])
+                                    .build(),
+                            )
+                            .build(),
+                    )
+                    .build(),
+            ))
+            .build();
+        let output = SvgRenderer::compact().render(&chart).to_string();
+
+        assert_eq!(output.matches("<use ").count(), 3);
+        assert_eq!(output.matches("<circle ").count(), 1);
+        assert!(output.contains(r##"href="#circle-10""##));
+        assert!(!output.contains("33.333"));
+    }
+
+    #[test]
+    fn symbols() {
+        use crate::element::Symbol;
+        use crate::series::Series;
+
+        let mut chart = chart();
+        let cartesian = cartesian(&mut chart);
+        let Series::Line(line) = &mut cartesian.series[0] else {
+            unreachable!()
+        };
+        line.symbol = Some(Symbol::Diamond);
+        line.symbol_rotation = Some(std::f64::consts::FRAC_PI_4);
+
+        let output = SvgRenderer::compact().render_to_string(&chart);
+        assert_eq!(
+            output.matches(r##"href="#symbol-diamond-2-45""##).count(),
+            3
+        );
+        assert!(!output.contains("<circle "));
+
+        let output = SvgRenderer::new().render(&chart).to_string();
+        assert_eq!(output.matches(r##" z" fill="#FFFFFFFF""##).count(), 3);
+    }
+
+    #[test]
+    fn donut() {
+        use crate::coordinate_system::{CoordinateSystem, Pie};
+
+        let chart = Chart::builder()
+            .size(400.0, 300.0)
+            .coordinate_system(CoordinateSystem::Pie(Box::new(
+                Pie::builder()
+                    .add_slice(("Rent", 3.0))
+                    .add_slice(("Food", 1.0))
+                    .inner_radius(50.0)
+                    .build(),
+            )))
+            .build();
+        let output = SvgRenderer::compact().render_to_string(&chart);
+
+        assert_eq!(output.matches(r#"<g class="slice""#).count(), 2);
+        // Three quarters of a ring around (200, 150), drawn in two arcs of at most half a turn.
+        assert!(output.contains("M200,82.5 A67.5,67.5,0,0,1,247.73,197.73"));
+        assert!(output.contains("L155,150 A45,45,0,0,0,231.82,181.82"));
+        assert!(output.contains("\nRent\n</text>"));
+
+        // A single slice is a whole disc without a radial edge to the center.
+        let chart = Chart::builder()
+            .size(400.0, 300.0)
+            .coordinate_system(CoordinateSystem::Pie(Box::new(
+                Pie::builder().add_slice(("All", 1.0)).build(),
+            )))
+            .aria(Aria::builder().data_table(true).build())
+            .build();
+        let output = SvgRenderer::compact().render_to_string(&chart);
+        assert!(!output.contains("L200,150"));
+        assert!(output.contains(r#"aria-label="Chart with 1 series""#));
+        assert!(output.contains("Pie series 1 with 1 slices: All 100%."));
+        assert_eq!(output.matches(r#"role="cell""#).count(), 3);
+    }
+
+    #[test]
+    fn polar() {
+        use crate::component::{CategoryAxis, ValueAxis};
+        use crate::coordinate_system::{CoordinateSystem, Polar};
+        use crate::series::Line;
+
+        let chart = Chart::builder()
+            .size(400.0, 300.0)
+            .coordinate_system(CoordinateSystem::Polar(Box::new(
+                Polar::builder()
+                    .angle_axis(
+                        CategoryAxis::builder()
+                            .data(bon::vec!["N", "E", "S", "W"])
+                            .build(),
+                    )
+                    .radius_axis(ValueAxis::builder().min(0.0).max(10.0).build())
+                    .add_series(Line::builder().data(vec![10.0, 5.0, 0.0, 2.5]).build())
+                    .build(),
+            )))
+            .build();
+        let output = SvgRenderer::compact().render_to_string(&chart);
+
+        assert!(output.contains(r#"<g class="axis angle-axis" id="angle-axis-0">"#));
+        assert!(output.contains(r#"<g class="axis radius-axis" id="radius-axis-0">"#));
+        assert!(output.contains(r#"<g class="series" id="series-0">"#));
+        assert!(output.contains("\nW\n</text>"));
+        // The first point sits on the outer circle at twelve o'clock.
+        assert!(output.contains("M200,82.5 L"));
+    }
+
+    #[test]
+    fn dataset() {
+        use crate::component::ValueAxis;
+        use crate::coordinate_system::{Cartesian, CoordinateSystem};
+        use crate::series::{Line, LineData, data::Dataset};
+
+        let build = |series: Line, dataset: Option<Dataset>| {
+            let mut chart = Chart::builder()
+                .size(400.0, 300.0)
+                .coordinate_system(CoordinateSystem::Cartesian(
+                    Cartesian::builder()
+                        .x_axis(ValueAxis::default())
+                        .y_axis(ValueAxis::default())
+                        .add_series(series)
+                        .build(),
+                ))
+                .build();
+            chart.dataset = dataset;
+            chart
+        };
+        let chart =
+            |series, dataset| SvgRenderer::compact().render_to_string(&build(series, dataset));
+        let dataset = Dataset::builder()
+            .add_dimension("latency", vec![120.0, 80.0, 95.0])
+            .add_dimension("date", vec![1.0, 2.0, 3.0])
+            .build();
+        let named = Line::builder()
+            .data(LineData::builder().x("date").y("latency").build())
+            .build();
+        let owned = Line::builder()
+            .data(vec![vec![120.0, 80.0, 95.0], vec![1.0, 2.0, 3.0]])
+            .build();
+
+        assert_eq!(chart(named, Some(dataset.clone())), chart(owned, None));
+
+        // Series reading a missing dimension are reported and left out.
+        let unknown = Line::builder()
+            .data(LineData::builder().x("date").y("p99").build())
+            .build();
+        let unknown = build(unknown, Some(dataset));
+        assert_eq!(unknown.unknown_dimensions(), ["p99"]);
+        let output = SvgRenderer::new().render_to_string(&unknown);
+        let series = &output[output.find(r#"id="series-0""#).unwrap()..];
+        assert!(!series.contains("<path"));
+    }
+
+    #[test]
+    fn radar() {
+        use crate::coordinate_system::{CoordinateSystem, Radar};
+        use crate::series::RadarSeries;
+
+        let chart = Chart::builder()
+            .size(400.0, 300.0)
+            .coordinate_system(CoordinateSystem::Radar(Box::new(
+                Radar::builder()
+                    .set_indicators([("Latency", 100.0), ("Errors", 10.0), ("Load", 1.0)])
+                    .add_series(
+                        RadarSeries::builder()
+                            .add_row([100.0, 5.0, 0.5])
+                            .add_row([50.0, 10.0, 0.0])
+                            .area_show(true)
+                            .build(),
+                    )
+                    .build(),
+            )))
+            .build();
+        let output = SvgRenderer::new().render_to_string(&chart);
+
+        assert_eq!(output.matches("<polygon ").count(), 2);
+        assert!(output.contains(r#"fill-opacity="0.3""#));
+        assert!(output.contains(r#"points="200,82.5 "#));
+        assert!(output.contains("\nLatency\n</text>"));
+
+        let mut chart = chart;
+        chart.aria = Some(Aria::builder().data_table(true).build());
+        let output = SvgRenderer::new().render_to_string(&chart);
+        assert!(output.contains(r#"aria-label="Chart with 1 series""#));
+        assert!(output.contains("Radar series 1 with 2 rows over 3 indicators."));
+        assert_eq!(output.matches(r#"role="cell""#).count(), 9);
+    }
+
+    #[test]
+    fn streaming() {
+        for renderer in [SvgRenderer::new(), SvgRenderer::compact()] {
+            let document = renderer.render(&chart()).to_string();
+            let streamed = renderer.render_to_string(&chart());
+
+            assert!(streamed.starts_with("<svg "));
+            assert!(streamed.trim_end().ends_with("</svg>"));
+            for tag in ["<g ", "</g>", "<path ", "<text ", "<rect ", "<style>"] {
+                assert_eq!(
+                    document.matches(tag).count(),
+                    streamed.matches(tag).count(),
+                    "{tag}"
+                );
+            }
         }
+
+        /// Accepts the opening tag, then fails every write.
+        struct Full(usize);
+        impl Write for Full {
+            fn write(&mut self, buf: &[u8]) -> Result<usize> {
+                self.0 += 1;
+                match self.0 {
+                    1 => Ok(buf.len()),
+                    _ => Err(io::ErrorKind::WriteZero.into()),
+                }
+            }
+            fn flush(&mut self) -> Result<()> {
+                Ok(())
+            }
+        }
+        let mut writer = Full(0);
+        let result = SvgRenderer::new().render_to_writer(&chart(), &mut writer);
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::WriteZero);
+        assert_eq!(writer.0, 2);
+    }
+
+    #[test]
+    fn aria() {
+        let mut chart = chart();
+        chart.aria = Some(Aria::builder().label("Weekly sales").build());
+        let output = SvgRenderer::new().render_to_string(&chart);
+
+        assert!(output.contains(r#"role="img""#));
+        assert!(output.contains(r#"aria-label="Weekly sales""#));
+        assert!(output.contains("<title>Weekly sales</title>"));
+        assert!(
+            output.contains("<desc>Line series 1 with 3 points, ranging from 150 to 230.</desc>")
+        );
+        assert!(!output.contains("data-table"));
+
+        chart.aria = Some(Aria::builder().data_table(true).build());
+        let output = SvgRenderer::new().render(&chart).to_string();
+
+        assert!(output.contains(r#"role="figure""#));
+        assert!(output.contains(r#"aria-label="Chart with 1 series""#));
+        assert_eq!(output.matches(r#"role="cell""#).count(), 6);
+        assert!(output.contains("Category"));
+        assert!(output.contains("Series 1"));
+        // Once as axis label and once in the table.
+        assert_eq!(output.matches("Tue").count(), 2);
+
+        // The title and description stay the first children with styles and definitions.
+        chart.aria = Some(Aria::builder().label("Weekly sales").build());
+        let output = SvgRenderer::compact().render(&chart).to_string();
+        let body = &output[output.find("<svg").unwrap()..];
+        let children = &body[body.find('>').unwrap() + 1..];
+        assert!(
+            children
+                .trim_start()
+                .starts_with("<title>Weekly sales</title>")
+        );
+        let desc = output.find("<desc>").unwrap();
+        assert!(desc < output.find("<style").unwrap());
+        assert!(desc < output.find("<defs").unwrap());
+    }
+
+    #[test]
+    fn mark_lines() {
+        use crate::component::{MarkAxis, MarkLine, MarkValue};
+        use crate::series::Series;
+
+        let mut chart = chart();
+        let cartesian = cartesian(&mut chart);
+        cartesian.mark_lines.push(
+            MarkLine::builder()
+                .axis(MarkAxis::Y)
+                .value(200.0)
+                .label("SLO")
+                .build(),
+        );
+        // Above the largest value of the axis.
+        cartesian.mark_lines.push(
+            MarkLine::builder()
+                .axis(MarkAxis::Y)
+                .value(1000.0)
+                .label("Limit")
+                .build(),
+        );
+        let Series::Line(line) = &mut cartesian.series[0] else {
+            unreachable!()
+        };
+        line.mark_lines.push(
+            MarkLine::builder()
+                .axis(MarkAxis::Y)
+                .value(MarkValue::Average)
+                .build(),
+        );
+        line.mark_lines.push(
+            MarkLine::builder()
+                .axis(MarkAxis::X)
+                .value(MarkValue::Max)
+                .build(),
+        );
+        let output = SvgRenderer::new().render_to_string(&chart);
+
+        assert_eq!(output.matches(r#"<g class="mark-line">"#).count(), 2);
+        assert!(output.contains("SLO"));
+        assert!(output.contains("201.33"));
+        assert!(output.contains(r#"stroke-dasharray="5,5""#));
+        assert!(!output.contains("Limit"));
+        assert_eq!(output.matches(r#"stroke-dasharray="5,5""#).count(), 3);
+    }
+
+    #[test]
+    fn mark_areas() {
+        use crate::component::MarkArea;
+
+        let mut chart = chart();
+        let cartesian = cartesian(&mut chart);
+        cartesian
+            .mark_areas
+            .push(MarkArea::builder().x(1.0, 2.0).label("Incident").build());
+        cartesian
+            .mark_areas
+            .push(MarkArea::builder().y(200.0, 1000.0).build());
+        let output = SvgRenderer::compact().render_to_string(&chart);
+
+        assert!(output.contains(r#"<g class="mark-area">"#));
+        // Tue and Wed of the three categories, the band on y is clipped to the plot area.
+        assert!(output.contains(r#"height="180" width="213.33" x="146.67" y="60""#));
+        assert!(output.contains(r#"height="36" width="320" x="40" y="60""#));
+        assert!(output.contains("Incident"));
+    }
+
+    #[test]
+    fn mark_points() {
+        use crate::component::{Anchor, MarkPoint, Marker};
+        use crate::series::Series;
+
+        let mut chart = chart();
+        let cartesian = cartesian(&mut chart);
+        cartesian.mark_points.push(
+            MarkPoint::builder()
+                .anchor(Anchor::Percentage(50.0, 10.0))
+                .marker(Marker::None)
+                .label("Deploy")
+                .build(),
+        );
+        cartesian.mark_points.push(
+            MarkPoint::builder()
+                .anchor(Anchor::Data(2.0, 100.0))
+                .marker(Marker::Arrow)
+                .label("Incident")
+                .build(),
+        );
+        cartesian.mark_points.push(
+            MarkPoint::builder()
+                .anchor(Anchor::Data(1.0, 1000.0))
+                .label("Outage")
+                .build(),
+        );
+        let Series::Line(line) = &mut cartesian.series[0] else {
+            unreachable!()
+        };
+        line.mark_points
+            .push(MarkPoint::builder().anchor(Anchor::Max).build());
+        let output = SvgRenderer::new().render_to_string(&chart);
+
+        assert_eq!(output.matches(r#"<g class="mark-point">"#).count(), 3);
+        assert!(output.contains("Deploy") && output.contains("Incident"));
+        assert!(!output.contains("Outage"));
+        assert!(output.contains("\n230\n"));
+        assert_eq!(output.matches("<polygon").count(), 2);
+
+        self::cartesian(&mut chart).series.clear();
+        let output = SvgRenderer::new().render_to_string(&chart);
+        assert!(output.contains("Deploy") && !output.contains("Incident"));
     }
 }
@@ -1,14 +1,12 @@
 use crate::chart::Chart;
-use crate::element::Offsets;
+use crate::utils::text::TextMeasurer;
 use kurbo::{Affine, BezPath, Circle, Line, Point, Rect, Shape, Stroke};
-use parley::{Alignment, AlignmentOptions};
-use parley::{FontContext, LayoutContext, PositionedLayoutItem, style::StyleProperty};
-use peniko::{Brush, Color, Fill};
+use parley::{Alignment, AlignmentOptions, PositionedLayoutItem};
+use peniko::{Brush, Color, Fill, Mix};
 use vello::Scene;
 
 pub struct VelloRenderer {
-    font_cx: FontContext,
-    layout_cx: LayoutContext,
+    pub text_measurer: TextMeasurer,
 }
 
 impl Default for VelloRenderer {
@@ -20,8 +18,7 @@ impl Default for VelloRenderer {
 impl VelloRenderer {
     pub fn new() -> Self {
         Self {
-            font_cx: FontContext::new(),
-            layout_cx: LayoutContext::new(),
+            text_measurer: TextMeasurer::new(),
         }
     }
 
@@ -34,7 +31,7 @@ impl VelloRenderer {
             &Rect::new(0.0, 0.0, chart.size.width, chart.size.height),
         );
 
-        let primitives = chart.generate_primitives();
+        let primitives = chart.generate_primitives(&self.text_measurer);
         for primitive in primitives {
             primitive.append_vello(scene, self);
         }
@@ -46,18 +43,20 @@ impl VelloRenderer {
         scene: &mut Scene,
         mouse_position: Option<Point>,
     ) {
-        let primitives = chart.generate_primitives();
+        let primitives = chart.generate_primitives(&self.text_measurer);
         for primitive in primitives {
             primitive.append_vello(scene, self);
         }
 
         if let Some(p) = mouse_position {
-            let offsets = Offsets::from_margin(&chart.size, &chart.margins);
-            if offsets.x_axis_start <= p.x
-                && p.x <= offsets.x_axis_end
-                && offsets.y_axis_start >= p.y
-                && p.y >= offsets.y_axis_end
-            {
+            let plot_areas = chart.plot_areas(&self.text_measurer);
+            // The last grid is drawn on top, so it is the one under the mouse.
+            if let Some(offsets) = plot_areas.iter().rev().find(|offsets| {
+                offsets.x_axis_start <= p.x
+                    && p.x <= offsets.x_axis_end
+                    && offsets.y_axis_start >= p.y
+                    && p.y >= offsets.y_axis_end
+            }) {
                 let x_dash_size = offsets.x_span / 45.0;
                 let y_dash_size = offsets.y_span / 45.0;
                 let brush = Brush::Solid(Color::from_rgba8(0xcc, 0xcc, 0xcc, 0xff));
@@ -99,13 +98,9 @@ impl AppendVello for crate::primitives::Line<'_> {
 
 impl AppendVello for crate::primitives::Text<'_> {
     fn append_vello(&self, scene: &mut Scene, vello_render: &mut VelloRenderer) {
-        let mut layout_builder =
-            vello_render
-                .layout_cx
-                .ranged_builder(&mut vello_render.font_cx, &self.text, 1.0);
-        layout_builder.push_default(StyleProperty::FontSize(self.font_size as f32));
-        let mut layout = layout_builder.build(&self.text);
-        layout.break_all_lines(None);
+        let mut layout = vello_render
+            .text_measurer
+            .layout(&self.text, self.font_size);
         layout.align(None, self.text_anchor, AlignmentOptions::default());
 
         let text_width = layout.width();
@@ -218,6 +213,95 @@ impl AppendVello for crate::primitives::Circle<'_> {
     }
 }
 
+impl AppendVello for crate::primitives::Arc<'_> {
+    fn append_vello(&self, scene: &mut Scene, _vello_render: &mut VelloRenderer) {
+        let path = self.path();
+        scene.fill(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            self.fill_color,
+            None,
+            &path,
+        );
+        scene.stroke(
+            self.stroke,
+            Affine::IDENTITY,
+            self.stroke_color,
+            None,
+            &path,
+        );
+    }
+}
+
+impl AppendVello for crate::primitives::Clip {
+    fn append_vello(&self, scene: &mut Scene, _vello_render: &mut VelloRenderer) {
+        scene.push_layer(Mix::Clip, 1.0, Affine::IDENTITY, &self.rect);
+    }
+}
+
+impl AppendVello for crate::primitives::Rect<'_> {
+    fn append_vello(&self, scene: &mut Scene, _vello_render: &mut VelloRenderer) {
+        scene.fill(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            self.fill_color,
+            None,
+            &self.rect,
+        );
+        // Vello draws zero-width strokes as hairlines, SVG does not draw them at all.
+        if self.stroke.width > 0.0 {
+            scene.stroke(
+                self.stroke,
+                Affine::IDENTITY,
+                self.stroke_color,
+                None,
+                &self.rect,
+            );
+        }
+    }
+}
+
+impl AppendVello for crate::primitives::Polygon<'_> {
+    fn append_vello(&self, scene: &mut Scene, _vello_render: &mut VelloRenderer) {
+        let mut path = BezPath::new();
+
+        for (index, point) in self.coords.iter().enumerate() {
+            if index == 0 {
+                path.move_to(*point);
+            } else {
+                path.line_to(*point);
+            }
+        }
+        path.close_path();
+
+        scene.fill(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            &self.fill_color.clone().multiply_alpha(self.fill_opacity),
+            None,
+            &path,
+        );
+        scene.stroke(
+            self.stroke,
+            Affine::IDENTITY,
+            self.stroke_color,
+            None,
+            &path,
+        );
+    }
+}
+
+impl AppendVello for crate::primitives::MultiSymbol<'_> {
+    fn append_vello(&self, scene: &mut Scene, _vello_render: &mut VelloRenderer) {
+        let shape = self.symbol.path(self.radius, self.rotation);
+        for coord in &self.coords {
+            let transform = Affine::translate((coord.x, coord.y));
+            scene.fill(Fill::NonZero, transform, self.fill_color, None, &shape);
+            scene.stroke(self.stroke, transform, self.stroke_color, None, &shape);
+        }
+    }
+}
+
 impl AppendVello for crate::primitives::MultiCircle<'_> {
     fn append_vello(&self, scene: &mut Scene, _vello_render: &mut VelloRenderer) {
         let circle = Circle::new((0.0, 0.0), self.radius).into_path(0.1);
@@ -0,0 +1,171 @@
+use arrow_array::{
+    Array, ArrowPrimitiveType, PrimitiveArray, RecordBatch, cast::AsArray, types::*,
+};
+use arrow_schema::{DataType, TimeUnit};
+
+use super::data::{Buffer, Dataset, Numbers, Numeric, PlotData};
+
+/// Shares the values without copying them if there are no nulls, nulls become NaN.
+fn numbers<T>(array: &PrimitiveArray<T>) -> Numbers
+where
+    T: ArrowPrimitiveType,
+    T::Native: Numeric,
+{
+    match array.null_count() {
+        0 => Buffer::shared(array.values().clone()).into(),
+        _ => floats(array, Numeric::to_f64).into(),
+    }
+}
+
+fn floats<T: ArrowPrimitiveType>(
+    array: &PrimitiveArray<T>,
+    to_f64: impl Fn(T::Native) -> f64,
+) -> Vec<f64> {
+    array
+        .iter()
+        .map(|value| value.map_or(f64::NAN, &to_f64))
+        .collect()
+}
+
+fn strings<'a>(values: impl Iterator<Item = Option<&'a str>>) -> PlotData {
+    PlotData::String(values.map(|v| v.unwrap_or_default().to_string()).collect())
+}
+
+impl PlotData {
+    /// Numeric, string, date and timestamp arrays, `None` for any other type.
+    #[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+    pub fn from_arrow(array: &dyn Array) -> Option<PlotData> {
+        let per_second = |unit: &TimeUnit| match unit {
+            TimeUnit::Second => 1.0,
+            TimeUnit::Millisecond => 1e3,
+            TimeUnit::Microsecond => 1e6,
+            TimeUnit::Nanosecond => 1e9,
+        };
+        Some(match array.data_type() {
+            DataType::Float64 => PlotData::Float(numbers(array.as_primitive::<Float64Type>())),
+            DataType::Float32 => PlotData::Float(numbers(array.as_primitive::<Float32Type>())),
+            DataType::Int32 => PlotData::Float(numbers(array.as_primitive::<Int32Type>())),
+            DataType::Int64 => PlotData::Float(numbers(array.as_primitive::<Int64Type>())),
+            DataType::UInt64 => PlotData::Float(numbers(array.as_primitive::<UInt64Type>())),
+            DataType::Int8 => floats(array.as_primitive::<Int8Type>(), f64::from).into(),
+            DataType::Int16 => floats(array.as_primitive::<Int16Type>(), f64::from).into(),
+            DataType::UInt8 => floats(array.as_primitive::<UInt8Type>(), f64::from).into(),
+            DataType::UInt16 => floats(array.as_primitive::<UInt16Type>(), f64::from).into(),
+            DataType::UInt32 => floats(array.as_primitive::<UInt32Type>(), f64::from).into(),
+            DataType::Utf8 => strings(array.as_string::<i32>().iter()),
+            DataType::LargeUtf8 => strings(array.as_string::<i64>().iter()),
+            DataType::Utf8View => strings(array.as_string_view().iter()),
+            DataType::Date32 => {
+                let days = floats(array.as_primitive::<Date32Type>(), |v| v as f64 * 86400.0);
+                PlotData::Time(days.into())
+            }
+            DataType::Date64 => {
+                let millis = floats(array.as_primitive::<Date64Type>(), |v| v as f64 / 1e3);
+                PlotData::Time(millis.into())
+            }
+            DataType::Timestamp(unit, _) => {
+                // All timestamp types store an i64 in their unit.
+                let per_second = per_second(unit);
+                let values = match unit {
+                    TimeUnit::Second => array.as_primitive::<TimestampSecondType>().values(),
+                    TimeUnit::Millisecond => {
+                        array.as_primitive::<TimestampMillisecondType>().values()
+                    }
+                    TimeUnit::Microsecond => {
+                        array.as_primitive::<TimestampMicrosecondType>().values()
+                    }
+                    TimeUnit::Nanosecond => {
+                        array.as_primitive::<TimestampNanosecondType>().values()
+                    }
+                };
+                let values =
+                    values
+                        .iter()
+                        .enumerate()
+                        .map(|(index, &v)| match array.is_null(index) {
+                            true => f64::NAN,
+                            false => v as f64 / per_second,
+                        });
+                PlotData::Time(values.collect::<Vec<f64>>().into())
+            }
+            _ => return None,
+        })
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+impl From<&RecordBatch> for Dataset {
+    /// One dimension per column named after its field, columns of other types are left out.
+    fn from(batch: &RecordBatch) -> Self {
+        let schema = batch.schema_ref();
+        let mut dataset = Dataset::default();
+        for (field, column) in schema.fields().iter().zip(batch.columns()) {
+            if let Some(data) = PlotData::from_arrow(column) {
+                dataset.dimensions.push((field.name().clone(), data));
+            }
+        }
+        dataset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{Float64Array, Int64Array, StringArray, TimestampMillisecondArray};
+
+    use super::*;
+    use crate::component::axis::CartesianAxis;
+
+    #[test]
+    fn record_batch() {
+        let latency = Arc::new(Float64Array::from(vec![12.5, 8.0, 9.5]));
+        let batch = RecordBatch::try_from_iter([
+            ("latency", latency.clone() as _),
+            (
+                "requests",
+                Arc::new(Int64Array::from(vec![Some(3), None, Some(7)])) as _,
+            ),
+            (
+                "service",
+                Arc::new(StringArray::from(vec!["api", "db", "api"])) as _,
+            ),
+            (
+                "time",
+                Arc::new(TimestampMillisecondArray::from(vec![0, 1500, 3009])) as _,
+            ),
+        ])
+        .unwrap();
+        let dataset = Dataset::from(&batch);
+
+        // Columns without nulls are shared with the batch.
+        let Some(PlotData::Float(Numbers::F64(values))) = dataset.get("latency") else {
+            panic!("float columns stay floats");
+        };
+        assert_eq!(values.as_ptr(), latency.values().as_ptr());
+        let Some(PlotData::Float(requests)) = dataset.get("requests") else {
+            panic!("integer columns are numbers");
+        };
+        assert!(requests.get(1).unwrap().is_nan());
+        assert_eq!(
+            dataset.get("service"),
+            Some(&PlotData::String(vec![
+                "api".to_string(),
+                "db".to_string(),
+                "api".to_string()
+            ]))
+        );
+        let Some(CartesianAxis::Category(axes)) = dataset.axis("service") else {
+            panic!("string columns are categories");
+        };
+        assert_eq!(axes[0].data, ["api", "db"]);
+        let Some(CartesianAxis::Value(axes)) = dataset.axis("time") else {
+            panic!("timestamps are plotted on value axes");
+        };
+        assert!(axes[0].time);
+        assert_eq!(
+            dataset.get("time"),
+            Some(&PlotData::Time(vec![0.0, 1.5, 3.009].into()))
+        );
+    }
+}
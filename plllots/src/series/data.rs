@@ -1,12 +1,195 @@
+use std::{borrow::Cow, fmt, ops::Deref, sync::Arc};
+
+use bon::Builder;
+
+use super::transform::{self, Transform};
+use crate::{
+    component::axis::{CartesianAxis, CategoryAxis, ValueAxis},
+    utils::get_raw_range,
+};
+
+/// Values owned by the series, or shared with other charts or owners without copying them,
+/// e.g. a memory-mapped file.
+#[derive(Clone)]
+pub enum Buffer<T> {
+    Owned(Vec<T>),
+    Arc(Arc<[T]>),
+    Shared(Arc<dyn AsRef<[T]> + Send + Sync>),
+}
+
+impl<T> Buffer<T> {
+    pub fn shared(values: impl AsRef<[T]> + Send + Sync + 'static) -> Self {
+        Self::Shared(Arc::new(values))
+    }
+}
+
+impl<T> Deref for Buffer<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        match self {
+            Buffer::Owned(values) => values,
+            Buffer::Arc(values) => values,
+            Buffer::Shared(values) => (**values).as_ref(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Buffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Buffer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T> From<Vec<T>> for Buffer<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self::Owned(value)
+    }
+}
+
+impl<T> From<Arc<[T]>> for Buffer<T> {
+    fn from(value: Arc<[T]>) -> Self {
+        Self::Arc(value)
+    }
+}
+
+/// A number type series data can be given in, converted to `f64` only when it is read.
+pub trait Numeric: Copy + Send + Sync + 'static {
+    fn to_f64(self) -> f64;
+
+    #[doc(hidden)]
+    fn into_numbers(values: Buffer<Self>) -> Numbers;
+}
+
+macro_rules! numeric {
+    ($($type:ty => $variant:ident),*) => {
+        $(
+            impl Numeric for $type {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn into_numbers(values: Buffer<Self>) -> Numbers {
+                    Numbers::$variant(values)
+                }
+            }
+        )*
+
+        /// A column of numbers, kept in the type they were given in.
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Numbers {
+            $($variant(Buffer<$type>),)*
+        }
+
+        impl Numbers {
+            pub fn len(&self) -> usize {
+                match self {
+                    $(Numbers::$variant(values) => values.len(),)*
+                }
+            }
+
+            pub fn get(&self, index: usize) -> Option<f64> {
+                match self {
+                    $(Numbers::$variant(values) => values.get(index).map(|v| v.to_f64()),)*
+                }
+            }
+
+            /// The numbers at `rows`, in their order and type.
+            pub(crate) fn select(&self, rows: &[usize]) -> Numbers {
+                match self {
+                    $(Numbers::$variant(values) => {
+                        let values: Vec<$type> = rows.iter().map(|&row| values[row]).collect();
+                        Numbers::$variant(values.into())
+                    })*
+                }
+            }
+        }
+    };
+}
+
+numeric!(f64 => F64, f32 => F32, i32 => I32, i64 => I64, u64 => U64);
+
+impl<T: Numeric> From<Vec<T>> for Numbers {
+    fn from(value: Vec<T>) -> Self {
+        T::into_numbers(value.into())
+    }
+}
+
+/// Copies the values, [`Buffer::shared`] or an `Arc<[T]>` share them instead.
+impl<T: Numeric> From<&[T]> for Numbers {
+    fn from(value: &[T]) -> Self {
+        T::into_numbers(value.to_vec().into())
+    }
+}
+
+impl<T: Numeric> From<Arc<[T]>> for Numbers {
+    fn from(value: Arc<[T]>) -> Self {
+        T::into_numbers(value.into())
+    }
+}
+
+impl<T: Numeric> From<Buffer<T>> for Numbers {
+    fn from(value: Buffer<T>) -> Self {
+        T::into_numbers(value)
+    }
+}
+
+impl Numbers {
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = f64> + Clone + '_ {
+        (0..self.len()).map(|index| self.get(index).unwrap_or(f64::NAN))
+    }
+
+    /// `(min, max)` ignoring NaN, both NaN without any number.
+    pub(crate) fn range(&self) -> (f64, f64) {
+        match self {
+            Numbers::F64(values) if !values.is_empty() => get_raw_range(values),
+            _ => self.iter().fold((f64::NAN, f64::NAN), |(min, max), v| {
+                (min.min(v), max.max(v))
+            }),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum PlotData {
-    Float(Vec<f64>),
+    Float(Numbers),
     String(Vec<String>),
+    /// Seconds since the Unix epoch, plotted like floats on a [time](ValueAxis::time) axis.
+    Time(Numbers),
 }
 
-impl From<Vec<f64>> for PlotData {
-    fn from(value: Vec<f64>) -> Self {
-        Self::Float(value)
+impl<T: Numeric> From<Vec<T>> for PlotData {
+    fn from(value: Vec<T>) -> Self {
+        Self::Float(value.into())
+    }
+}
+
+/// Copies the values, [`Buffer::shared`] or an `Arc<[T]>` share them instead.
+impl<T: Numeric> From<&[T]> for PlotData {
+    fn from(value: &[T]) -> Self {
+        Self::Float(value.into())
+    }
+}
+
+impl<T: Numeric> From<Arc<[T]>> for PlotData {
+    fn from(value: Arc<[T]>) -> Self {
+        Self::Float(value.into())
+    }
+}
+
+impl<T: Numeric> From<Buffer<T>> for PlotData {
+    fn from(value: Buffer<T>) -> Self {
+        Self::Float(value.into())
     }
 }
 
@@ -15,3 +198,164 @@ impl From<Vec<String>> for PlotData {
         Self::String(value)
     }
 }
+
+impl PlotData {
+    pub fn len(&self) -> usize {
+        match self {
+            PlotData::Float(data) | PlotData::Time(data) => data.len(),
+            PlotData::String(data) => data.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub(crate) fn get_float(&self, index: usize) -> Option<f64> {
+        self.as_numbers()?.get(index)
+    }
+
+    /// The values formatted for display.
+    pub(crate) fn to_strings(&self) -> Vec<String> {
+        match self {
+            PlotData::Float(data) | PlotData::Time(data) => {
+                data.iter().map(|v| v.to_string()).collect()
+            }
+            PlotData::String(data) => data.clone(),
+        }
+    }
+
+    pub(crate) fn as_numbers(&self) -> Option<&Numbers> {
+        match self {
+            PlotData::Float(data) | PlotData::Time(data) => Some(data),
+            PlotData::String(_) => None,
+        }
+    }
+}
+
+/// Named columns shared by the series of a chart, which refer to them by name instead of
+/// owning a copy of their data.
+#[derive(Debug, Builder, Clone, Default, PartialEq)]
+pub struct Dataset {
+    #[builder(field)]
+    pub dimensions: Vec<(String, PlotData)>,
+    /// Run in order on the dimensions before the chart is laid out.
+    #[builder(field)]
+    pub transforms: Vec<Transform>,
+}
+
+impl<S: dataset_builder::State> DatasetBuilder<S> {
+    pub fn add_dimension(mut self, name: impl Into<String>, data: impl Into<PlotData>) -> Self {
+        self.dimensions.push((name.into(), data.into()));
+        self
+    }
+
+    pub fn add_transform(mut self, transform: Transform) -> Self {
+        self.transforms.push(transform);
+        self
+    }
+
+    pub fn set_transforms(mut self, transforms: impl IntoIterator<Item = Transform>) -> Self {
+        self.transforms = transforms.into_iter().collect();
+        self
+    }
+}
+
+impl Dataset {
+    /// The dimension called `name`, the first one if several share it.
+    pub fn get(&self, name: &str) -> Option<&PlotData> {
+        self.dimensions
+            .iter()
+            .find(|(dimension, _)| dimension == name)
+            .map(|(_, data)| data)
+    }
+
+    /// An axis fitting the dimension called `name`: a category axis with its distinct values
+    /// for strings, a value axis for numbers and a [time](ValueAxis::time) axis for times.
+    pub fn axis(&self, name: &str) -> Option<CartesianAxis> {
+        Some(match self.get(name)? {
+            PlotData::String(values) => {
+                let mut categories: Vec<String> = Vec::new();
+                for value in values {
+                    if !categories.contains(value) {
+                        categories.push(value.clone());
+                    }
+                }
+                CategoryAxis::builder().data(categories).build().into()
+            }
+            PlotData::Float(_) => ValueAxis::default().into(),
+            PlotData::Time(_) => ValueAxis::builder().time(true).build().into(),
+        })
+    }
+
+    /// The dataset after its transforms, borrowed if it has none.
+    pub fn transformed(&self) -> Cow<'_, Dataset> {
+        if self.transforms.is_empty() {
+            return Cow::Borrowed(self);
+        }
+        Cow::Owned(Dataset {
+            dimensions: transform::run(self),
+            transforms: Vec::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric_types() {
+        let counters = Numbers::from(vec![3_i64, -2, 40]);
+        assert_eq!(counters, Numbers::I64(vec![3, -2, 40].into()));
+        assert_eq!(counters.range(), (-2.0, 40.0));
+
+        let telemetry = Numbers::from(&[1.5_f32, f32::NAN, 0.25][..]);
+        assert_eq!(telemetry.get(2), Some(0.25));
+        assert_eq!(telemetry.range(), (0.25, 1.5));
+    }
+
+    #[test]
+    fn shared_buffers() {
+        use crate::series::{Line, Series};
+
+        let buffer: Arc<[f64]> = vec![1.0, 4.0, 2.0].into();
+        let line: Series = Line::builder().data(buffer.clone()).build().into();
+        let copy = line.clone();
+        // Clones of the series read the same memory.
+        let Some(Numbers::F64(values)) = copy.float_data(true, None) else {
+            panic!("the line reads its buffer");
+        };
+        assert!(matches!(values, Buffer::Arc(_)));
+        assert_eq!(values.as_ptr(), buffer.as_ptr());
+        assert_eq!(values.len(), 3);
+
+        // Anything exposing a slice, e.g. a view into a memory-mapped file.
+        struct Mapped(Vec<f64>);
+        impl AsRef<[f64]> for Mapped {
+            fn as_ref(&self) -> &[f64] {
+                &self.0[1..]
+            }
+        }
+        let mapped = Numbers::from(Buffer::shared(Mapped(vec![9.0, 1.0, 2.0])));
+        assert_eq!(mapped, Numbers::F64(vec![1.0, 2.0].into()));
+    }
+
+    #[test]
+    fn axes() {
+        let dataset = Dataset::builder()
+            .add_dimension("day", PlotData::Time(vec![0.0, 86400.0].into()))
+            .add_dimension("latency", vec![1.0, 2.0])
+            .build();
+
+        let Some(CartesianAxis::Value(days)) = dataset.axis("day") else {
+            panic!("times are plotted on value axes");
+        };
+        assert!(days[0].time);
+        assert_eq!(days[0].label(86400.0, 21600.0), "1970-01-02");
+        let Some(CartesianAxis::Value(latency)) = dataset.axis("latency") else {
+            panic!("numbers are plotted on value axes");
+        };
+        assert_eq!(latency[0].label(2.5, 0.5), "2.5");
+    }
+}
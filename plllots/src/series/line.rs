@@ -1,16 +1,25 @@
+use std::{borrow::Cow, sync::Arc};
+
 use bon::Builder;
 use kurbo::{Point, Stroke};
 use peniko::Brush;
 
 use crate::{
     chart::{ChartHelper, Theme},
-    component::SingleCartesianAxis,
+    component::{DataLabel, MarkLine, MarkPoint, SingleCartesianAxis},
+    element::Symbol,
     primitives::Primitives,
+    series::{
+        cartesian_coord,
+        data::{Dataset, Numbers, Numeric},
+    },
     utils::lttb::lttb_optimized_memory,
 };
 
 #[derive(Debug, Builder, Clone, PartialEq)]
 pub struct Line {
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub name: Option<String>,
     #[builder(setters(option_fn(vis = "")))]
     pub stroke: Option<Stroke>,
     #[builder(setters(option_fn(vis = "")))]
@@ -22,6 +31,11 @@ pub struct Line {
     #[builder(setters(option_fn(vis = "")))]
     pub symbol_show: Option<bool>,
     #[builder(setters(option_fn(vis = "")))]
+    pub symbol: Option<Symbol>,
+    /// Rotation of the symbols in radians, clockwise.
+    #[builder(setters(option_fn(vis = "")))]
+    pub symbol_rotation: Option<f64>,
+    #[builder(setters(option_fn(vis = "")))]
     pub symbol_stroke: Option<Stroke>,
     #[builder(setters(option_fn(vis = "")))]
     pub symbol_stroke_color: Option<Brush>,
@@ -29,6 +43,15 @@ pub struct Line {
     pub symbol_fill_color: Option<Brush>,
     #[builder(setters(option_fn(vis = "")))]
     pub symbol_size: Option<f64>,
+    /// Clips the series to the plot area.
+    #[builder(default = true, setters(option_fn(vis = "")))]
+    pub clip: bool,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label: Option<DataLabel>,
+    #[builder(default)]
+    pub mark_lines: Vec<MarkLine>,
+    #[builder(default)]
+    pub mark_points: Vec<MarkPoint>,
     #[builder(into)]
     pub data: LineData,
 }
@@ -41,27 +64,69 @@ pub struct LineData {
     pub secondary_data_index: usize,
     #[builder(setters(option_fn(vis = "")))]
     pub lttb: Option<usize>,
-    pub data: Vec<Vec<f64>>,
+    /// Dimension of the dataset of the chart read instead of the secondary data.
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub x: Option<String>,
+    /// Dimension of the dataset of the chart read instead of the primary data.
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub y: Option<String>,
+    #[builder(default, with = |data: impl IntoIterator<Item: Into<Numbers>>| data.into_iter().map(Into::into).collect())]
+    pub data: Vec<Numbers>,
 }
 
-impl From<Vec<f64>> for LineData {
-    fn from(value: Vec<f64>) -> Self {
+impl<T: Numeric> From<Vec<T>> for LineData {
+    fn from(value: Vec<T>) -> Self {
         LineData {
             primary_data_index: 0,
             secondary_data_index: 1,
             lttb: None,
-            data: vec![value],
+            x: None,
+            y: None,
+            data: vec![value.into()],
         }
     }
 }
 
-impl From<Vec<Vec<f64>>> for LineData {
-    fn from(value: Vec<Vec<f64>>) -> Self {
+impl<T: Numeric> From<Arc<[T]>> for LineData {
+    fn from(value: Arc<[T]>) -> Self {
         LineData {
             primary_data_index: 0,
             secondary_data_index: 1,
             lttb: None,
-            data: value,
+            x: None,
+            y: None,
+            data: vec![value.into()],
+        }
+    }
+}
+
+impl<T: Numeric> From<Vec<Vec<T>>> for LineData {
+    fn from(value: Vec<Vec<T>>) -> Self {
+        LineData {
+            primary_data_index: 0,
+            secondary_data_index: 1,
+            lttb: None,
+            x: None,
+            y: None,
+            data: value.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl LineData {
+    /// The primary or secondary values, looked up in `dataset` if they name a dimension.
+    pub(crate) fn values<'a>(
+        &'a self,
+        primary: bool,
+        dataset: Option<&'a Dataset>,
+    ) -> Option<&'a Numbers> {
+        let (dimension, index) = match primary {
+            true => (&self.y, self.primary_data_index),
+            false => (&self.x, self.secondary_data_index),
+        };
+        match dimension {
+            Some(name) => dataset?.get(name)?.as_numbers(),
+            None => self.data.get(index),
         }
     }
 }
@@ -75,7 +140,10 @@ impl Line {
         helper: &ChartHelper,
         primitives: &mut Vec<Primitives<'a>>,
         theme: &'a Theme,
-    ) {
+    ) -> Vec<Primitives<'a>> {
+        let Some(primary) = self.data.values(true, helper.dataset) else {
+            return Vec::new();
+        };
         match (x_axis, y_axis) {
             (SingleCartesianAxis::Category(_x_axis), SingleCartesianAxis::Category(_y_axis)) => {
                 todo!()
@@ -88,16 +156,14 @@ impl Line {
                 let y_pos =
                     y_axis.pos_closure(&crate::component::AxisType::YAxis, &y_helper, helper);
 
-                let primary_data_index = self.data.primary_data_index;
                 self.draw(
                     primitives,
+                    helper,
                     theme,
                     series_index,
-                    &x_pos,
-                    &y_pos,
-                    primary_data_index,
-                    primary_data_index,
-                );
+                    &cartesian_coord(x_pos, y_pos),
+                    (primary, primary),
+                )
             }
             (
                 SingleCartesianAxis::Value((x_axis, x_helper)),
@@ -107,16 +173,14 @@ impl Line {
                     x_axis.pos_closure(&crate::component::AxisType::XAxis, &x_helper, helper);
                 let y_pos = y_axis.pos_closure(&crate::component::AxisType::YAxis, helper);
 
-                let primary_data_index = self.data.primary_data_index;
                 self.draw(
                     primitives,
+                    helper,
                     theme,
                     series_index,
-                    &x_pos,
-                    &y_pos,
-                    primary_data_index,
-                    primary_data_index,
-                );
+                    &cartesian_coord(x_pos, y_pos),
+                    (primary, primary),
+                )
             }
             (
                 SingleCartesianAxis::Value((x_axis, x_helper)),
@@ -127,31 +191,31 @@ impl Line {
                 let y_pos =
                     y_axis.pos_closure(&crate::component::AxisType::YAxis, &y_helper, helper);
 
-                let primary_data_index = self.data.primary_data_index;
-                let secondary_data_index = self.data.secondary_data_index;
+                let Some(secondary) = self.data.values(false, helper.dataset) else {
+                    return Vec::new();
+                };
                 self.draw(
                     primitives,
+                    helper,
                     theme,
                     series_index,
-                    &x_pos,
-                    &y_pos,
-                    primary_data_index,
-                    secondary_data_index,
-                );
+                    &cartesian_coord(x_pos, y_pos),
+                    (primary, secondary),
+                )
             }
-        };
+        }
     }
 
-    fn draw<'a>(
+    /// Draws the series and returns its data labels, so that they can be drawn unclipped.
+    pub(crate) fn draw<'a>(
         &'a self,
         primitives: &mut Vec<Primitives<'a>>,
+        helper: &ChartHelper,
         theme: &'a Theme,
         series_index: usize,
-        x_pos: &impl Fn(Option<usize>, Option<f64>) -> f64,
-        y_pos: &impl Fn(Option<usize>, Option<f64>) -> f64,
-        primary_data_index: usize,
-        secondary_data_index: usize,
-    ) {
+        coord: &impl Fn(usize, Option<f64>, Option<f64>) -> Point,
+        (primary, secondary): (&Numbers, &Numbers),
+    ) -> Vec<Primitives<'a>> {
         let mut path = crate::primitives::Path {
             stroke: self.stroke.as_ref().unwrap_or(&theme.line.stroke),
             stroke_color: self
@@ -160,65 +224,98 @@ impl Line {
                 .unwrap_or(&theme.series_colors[series_index % theme.series_colors.len()]),
             coords: Vec::new(),
         };
-        match self.data.lttb {
+        let primary_values: Cow<Numbers> = match self.data.lttb {
             Some(t) => {
                 // TODO probably need to keep original index
                 path.coords.reserve(t);
-                let (primary_values, secondary_values) = lttb_optimized_memory(
-                    &self.data.data[primary_data_index],
-                    &self.data.data[secondary_data_index],
-                    t,
-                );
+                let (primary_values, secondary_values) =
+                    lttb_optimized_memory(primary, secondary, t);
                 for (index, (primary_value, secondary_value)) in primary_values
                     .iter()
                     .zip(secondary_values.iter())
                     .enumerate()
                 {
-                    path.coords.push(Point::new(
-                        x_pos(Some(index), Some(*secondary_value)),
-                        y_pos(Some(index), Some(*primary_value)),
-                    ));
+                    path.coords
+                        .push(coord(index, Some(*secondary_value), Some(*primary_value)));
                 }
+                Cow::Owned(primary_values.into())
             }
             None => {
-                path.coords
-                    .reserve(self.data.data[primary_data_index].len());
-                for (index, (primary_value, secondary_value)) in self.data.data[primary_data_index]
-                    .iter()
-                    .zip(self.data.data[secondary_data_index].iter())
-                    .enumerate()
+                path.coords.reserve(primary.len());
+                for (index, (primary_value, secondary_value)) in
+                    primary.iter().zip(secondary.iter()).enumerate()
                 {
-                    path.coords.push(Point::new(
-                        x_pos(Some(index), Some(*secondary_value)),
-                        y_pos(Some(index), Some(*primary_value)),
-                    ));
+                    path.coords
+                        .push(coord(index, Some(secondary_value), Some(primary_value)));
                 }
+                Cow::Borrowed(primary)
             }
-        }
+        };
+
+        let symbol_show = self.symbol_show.unwrap_or(theme.line.symbol_show);
+        // Labels are drawn on top of the line and its symbols.
+        let labels = self.label.as_ref().map(|label| {
+            let radius = match symbol_show {
+                true => self.symbol_size.unwrap_or(theme.line.symbol_size),
+                false => 0.0,
+            };
+            let points = path.coords.iter().zip(primary_values.iter());
+            let mut labels = Vec::new();
+            label.draw(
+                points.map(|(coord, value)| (*coord, value, radius)),
+                helper,
+                &mut labels,
+                theme,
+            );
+            labels
+        });
 
-        if self.symbol_show.unwrap_or(theme.line.symbol_show) {
-            let nulti_circle =
-                crate::primitives::Primitives::MultiCircle(crate::primitives::MultiCircle {
-                    stroke: self
-                        .symbol_stroke
-                        .as_ref()
-                        .unwrap_or(&theme.line.symbol_stroke),
-                    stroke_color: self
-                        .symbol_stroke_color
-                        .as_ref()
-                        .unwrap_or(&theme.series_colors[series_index % theme.series_colors.len()]),
-                    fill_color: self
-                        .symbol_fill_color
-                        .as_ref()
-                        .unwrap_or(&theme.line.symbol_fill_color),
-                    // TODO: find a way to remove this clone
-                    coords: path.coords.clone(),
-                    radius: self.symbol_size.unwrap_or(theme.line.symbol_size),
-                });
-            primitives.push(crate::primitives::Primitives::Path(path));
-            primitives.push(nulti_circle);
+        if symbol_show {
+            let symbols = crate::primitives::MultiSymbol {
+                stroke: self
+                    .symbol_stroke
+                    .as_ref()
+                    .unwrap_or(&theme.line.symbol_stroke),
+                stroke_color: self
+                    .symbol_stroke_color
+                    .as_ref()
+                    .unwrap_or(&theme.series_colors[series_index % theme.series_colors.len()]),
+                fill_color: self
+                    .symbol_fill_color
+                    .as_ref()
+                    .unwrap_or(&theme.line.symbol_fill_color),
+                symbol: self.symbol.as_ref().unwrap_or(&theme.line.symbol),
+                rotation: self.symbol_rotation,
+                // TODO: find a way to remove this clone
+                coords: path
+                    .coords
+                    .iter()
+                    .copied()
+                    .filter(|coord| coord.is_finite())
+                    .collect(),
+                radius: self.symbol_size.unwrap_or(theme.line.symbol_size),
+            };
+            push_segments(path, primitives);
+            primitives.push(symbols.into_primitive());
         } else {
-            primitives.push(crate::primitives::Primitives::Path(path));
+            push_segments(path, primitives);
+        }
+        labels.unwrap_or_default()
+    }
+}
+
+/// Pushes `path`, split into separate paths at missing values so they leave gaps.
+fn push_segments<'a>(path: crate::primitives::Path<'a>, primitives: &mut Vec<Primitives<'a>>) {
+    if path.coords.iter().all(|coord| coord.is_finite()) {
+        primitives.push(Primitives::Path(path));
+        return;
+    }
+    for segment in path.coords.split(|coord| !coord.is_finite()) {
+        if !segment.is_empty() {
+            primitives.push(Primitives::Path(crate::primitives::Path {
+                coords: segment.to_vec(),
+                ..path.clone()
+            }));
         }
     }
 }
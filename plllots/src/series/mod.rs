@@ -1,10 +1,26 @@
+#[cfg(feature = "arrow")]
+mod arrow;
 pub mod data;
 pub mod line;
+#[cfg(feature = "polars")]
+mod polars;
+pub mod radar;
 pub mod scatter;
+pub mod transform;
 
 pub use line::*;
+pub use radar::*;
 pub use scatter::*;
 
+use crate::{
+    chart::{ChartHelper, Theme},
+    component::{MarkLine, MarkPoint},
+    primitives::Primitives,
+};
+use data::{Dataset, Numbers, PlotData};
+use kurbo::Point;
+use peniko::Brush;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Series {
     Line(Line),
@@ -37,4 +53,209 @@ impl Series {
             Series::Scatter(scatter) => scatter.y_axis_index,
         }
     }
+
+    pub(crate) fn mark_lines(&self) -> &[MarkLine] {
+        match self {
+            Series::Line(line) => &line.mark_lines,
+            Series::Scatter(scatter) => &scatter.mark_lines,
+        }
+    }
+
+    pub(crate) fn mark_points(&self) -> &[MarkPoint] {
+        match self {
+            Series::Line(line) => &line.mark_points,
+            Series::Scatter(scatter) => &scatter.mark_points,
+        }
+    }
+
+    pub(crate) fn clip(&self) -> bool {
+        match self {
+            Series::Line(line) => line.clip,
+            Series::Scatter(scatter) => scatter.clip,
+        }
+    }
+
+    /// Draws the series with `coord` placing its points, the secondary data is only used if
+    /// both axes show values.
+    pub(crate) fn draw<'a>(
+        &'a self,
+        series_index: usize,
+        coord: &impl Fn(usize, Option<f64>, Option<f64>) -> Point,
+        secondary: bool,
+        helper: &ChartHelper,
+        primitives: &mut Vec<Primitives<'a>>,
+        theme: &'a Theme,
+    ) {
+        let dataset = helper.dataset;
+        match self {
+            Series::Line(line) => {
+                let Some(primary) = line.data.values(true, dataset) else {
+                    return;
+                };
+                let secondary = match secondary {
+                    true => match line.data.values(false, dataset) {
+                        Some(secondary) => secondary,
+                        None => return,
+                    },
+                    false => primary,
+                };
+                let labels = line.draw(
+                    primitives,
+                    helper,
+                    theme,
+                    series_index,
+                    coord,
+                    (primary, secondary),
+                );
+                primitives.extend(labels);
+            }
+            Series::Scatter(scatter) => {
+                let Some(primary) = scatter.data.values(true, dataset) else {
+                    return;
+                };
+                let secondary = match secondary {
+                    true => match scatter.data.values(false, dataset) {
+                        Some(secondary) => secondary,
+                        None => return,
+                    },
+                    false => primary,
+                };
+                let labels = scatter.draw(
+                    primitives,
+                    helper,
+                    theme,
+                    series_index,
+                    coord,
+                    (primary, secondary),
+                );
+                primitives.extend(labels);
+            }
+        }
+    }
+
+    /// The color the series is drawn in, `index` is its position among the drawn series.
+    pub(crate) fn color<'a>(&'a self, index: usize, theme: &'a Theme) -> &'a Brush {
+        let color = match self {
+            Series::Line(line) => line.color.as_ref(),
+            Series::Scatter(scatter) => scatter.fill_color.as_ref(),
+        };
+        color.unwrap_or(&theme.series_colors[index % theme.series_colors.len()])
+    }
+
+    /// Names of the dimensions of the dataset the series reads.
+    pub(crate) fn dimensions(&self) -> impl Iterator<Item = &str> {
+        let dimensions = match self {
+            Series::Line(line) => [&line.data.x, &line.data.y, &None],
+            Series::Scatter(scatter) => [&scatter.data.x, &scatter.data.y, &scatter.data.size],
+        };
+        dimensions.into_iter().filter_map(Option::as_deref)
+    }
+
+    /// The primary or secondary data, if it is numeric.
+    pub(crate) fn float_data<'a>(
+        &'a self,
+        primary: bool,
+        dataset: Option<&'a Dataset>,
+    ) -> Option<&'a Numbers> {
+        match self {
+            Series::Line(line) => line.data.values(primary, dataset),
+            Series::Scatter(scatter) => scatter.data.values(primary, dataset)?.as_numbers(),
+        }
+    }
+}
+
+/// Maps the `(index, secondary value, primary value)` of a data point to pixels with one
+/// closure per cartesian axis.
+pub(crate) fn cartesian_coord(
+    x_pos: impl Fn(Option<usize>, Option<f64>) -> f64,
+    y_pos: impl Fn(Option<usize>, Option<f64>) -> f64,
+) -> impl Fn(usize, Option<f64>, Option<f64>) -> Point {
+    move |index, secondary, primary| {
+        Point::new(x_pos(Some(index), secondary), y_pos(Some(index), primary))
+    }
+}
+
+impl Series {
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Series::Line(line) => line.name.as_deref(),
+            Series::Scatter(scatter) => scatter.name.as_deref(),
+        }
+    }
+
+    /// The name of the series, or its position among all series of the chart.
+    pub(crate) fn display_name(&self, index: usize) -> String {
+        match self.name() {
+            Some(name) => name.to_string(),
+            None => format!("Series {}", index + 1),
+        }
+    }
+
+    /// Describes the series in one sentence, e.g. for screen readers.
+    pub(crate) fn summary(&self, index: usize, dataset: Option<&Dataset>) -> String {
+        let kind = match self {
+            Series::Line(_) => "Line",
+            Series::Scatter(_) => "Scatter",
+        };
+        let values = self.float_data(true, dataset);
+        let name = match self.name() {
+            Some(name) => format!("{kind} series \"{name}\""),
+            None => format!("{kind} series {}", index + 1),
+        };
+        match values.filter(|v| v.iter().any(|v| !v.is_nan())) {
+            Some(values) => {
+                let (min, max) = values.range();
+                format!(
+                    "{name} with {} points, ranging from {min} to {max}.",
+                    values.len()
+                )
+            }
+            None => format!("{name} without numeric points."),
+        }
+    }
+
+    /// The data of the series as labelled columns of formatted values: the `categories` of
+    /// its axis or its secondary values, its primary values under the name of the series and
+    /// the symbol sizes. Columns read from `dataset` are labelled with their dimension.
+    pub(crate) fn columns(
+        &self,
+        index: usize,
+        dataset: Option<&Dataset>,
+        categories: Option<&[String]>,
+    ) -> Vec<(String, Vec<String>)> {
+        let name = self.display_name(index);
+        let (x, y) = match self {
+            Series::Line(line) => (&line.data.x, &line.data.y),
+            Series::Scatter(scatter) => (&scatter.data.x, &scatter.data.y),
+        };
+        let strings = |primary| match self {
+            Series::Line(line) => line
+                .data
+                .values(primary, dataset)
+                .map(|values| values.iter().map(|v| v.to_string()).collect()),
+            Series::Scatter(scatter) => scatter
+                .data
+                .values(primary, dataset)
+                .map(PlotData::to_strings),
+        };
+
+        let mut columns = Vec::new();
+        match categories {
+            Some(categories) => columns.push(("Category".to_string(), categories.to_vec())),
+            None => columns.extend(
+                strings(false).map(|values| (x.clone().unwrap_or_else(|| "X".to_string()), values)),
+            ),
+        }
+        columns.extend(strings(true).map(|values| (y.clone().unwrap_or(name), values)));
+        if let Series::Scatter(scatter) = self
+            && let Some(sizes) = scatter.data.sizes(dataset)
+        {
+            let header = scatter.data.size.clone();
+            columns.push((
+                header.unwrap_or_else(|| "Size".to_string()),
+                sizes.to_strings(),
+            ));
+        }
+        columns
+    }
 }
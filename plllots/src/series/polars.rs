@@ -0,0 +1,127 @@
+use ::polars::prelude::{
+    ChunkedArray, DataFrame, DataType, PolarsNumericType, Series as PolarsSeries, TimeUnit,
+};
+
+use super::data::{Buffer, Dataset, Numbers, Numeric, PlotData};
+
+/// Shares the values without copying them if they are contiguous, nulls become NaN.
+fn numbers<T>(array: &ChunkedArray<T>) -> Numbers
+where
+    T: PolarsNumericType,
+    T::Native: Numeric,
+{
+    match array.cont_slice() {
+        // The values buffer of the only chunk, shared with the data frame.
+        Ok(_) => Buffer::shared(array.downcast_as_array().values().clone()).into(),
+        Err(_) => floats(array, Numeric::to_f64).into(),
+    }
+}
+
+fn floats<T: PolarsNumericType>(
+    array: &ChunkedArray<T>,
+    to_f64: impl Fn(T::Native) -> f64,
+) -> Vec<f64> {
+    array
+        .iter()
+        .map(|value| value.map_or(f64::NAN, &to_f64))
+        .collect()
+}
+
+fn strings(series: &PolarsSeries) -> Option<PlotData> {
+    let values = series.str().ok()?.iter();
+    Some(PlotData::String(
+        values.map(|v| v.unwrap_or_default().to_string()).collect(),
+    ))
+}
+
+impl PlotData {
+    /// Numeric, string, categorical, date and datetime series, `None` for any other type.
+    #[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
+    pub fn from_polars(series: &PolarsSeries) -> Option<PlotData> {
+        Some(match series.dtype() {
+            DataType::Float64 => PlotData::Float(numbers(series.f64().ok()?)),
+            DataType::Float32 => PlotData::Float(numbers(series.f32().ok()?)),
+            DataType::Int32 => PlotData::Float(numbers(series.i32().ok()?)),
+            DataType::Int64 => PlotData::Float(numbers(series.i64().ok()?)),
+            DataType::UInt64 => PlotData::Float(numbers(series.u64().ok()?)),
+            DataType::Int8
+            | DataType::Int16
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32 => {
+                let series = series.cast(&DataType::Float64).ok()?;
+                floats(series.f64().ok()?, |v| v).into()
+            }
+            DataType::String => strings(series)?,
+            // Only available with the `dtype-categorical` feature of polars.
+            dtype if dtype.is_categorical() || dtype.is_enum() => {
+                strings(&series.cast(&DataType::String).ok()?)?
+            }
+            DataType::Date => {
+                let days = series.to_physical_repr();
+                PlotData::Time(floats(days.i32().ok()?, |v| v as f64 * 86400.0).into())
+            }
+            DataType::Datetime(unit, _) => {
+                let per_second = match unit {
+                    TimeUnit::Milliseconds => 1e3,
+                    TimeUnit::Microseconds => 1e6,
+                    TimeUnit::Nanoseconds => 1e9,
+                };
+                let values = series.to_physical_repr();
+                PlotData::Time(floats(values.i64().ok()?, |v| v as f64 / per_second).into())
+            }
+            _ => return None,
+        })
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
+impl From<&DataFrame> for Dataset {
+    /// One dimension per column, columns of other types are left out.
+    fn from(frame: &DataFrame) -> Self {
+        let mut dataset = Dataset::default();
+        for column in frame.get_columns() {
+            if let Some(data) = PlotData::from_polars(column.as_materialized_series()) {
+                dataset.dimensions.push((column.name().to_string(), data));
+            }
+        }
+        dataset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::polars::prelude::Column;
+
+    use super::*;
+
+    #[test]
+    fn data_frame() {
+        let frame = DataFrame::new(vec![
+            Column::new("latency".into(), [12.5_f64, 8.0, 9.5]),
+            Column::new("requests".into(), [Some(3_i64), None, Some(7)]),
+            Column::new("service".into(), ["api", "db", "api"]),
+        ])
+        .unwrap();
+        let dataset = Dataset::from(&frame);
+
+        let Some(PlotData::Float(Numbers::F64(values))) = dataset.get("latency") else {
+            panic!("float columns stay floats");
+        };
+        let column = frame.column("latency").unwrap().f64().unwrap();
+        assert_eq!(values.as_ptr(), column.cont_slice().unwrap().as_ptr());
+        let Some(PlotData::Float(requests)) = dataset.get("requests") else {
+            panic!("integer columns are numbers");
+        };
+        assert_eq!(requests.get(2), Some(7.0));
+        assert!(requests.get(1).unwrap().is_nan());
+        assert_eq!(
+            dataset.get("service"),
+            Some(&PlotData::String(vec![
+                "api".to_string(),
+                "db".to_string(),
+                "api".to_string()
+            ]))
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use bon::Builder;
+use kurbo::Stroke;
+use peniko::Brush;
+
+use crate::element::Symbol;
+
+/// Rows of values on the indicators of a [`crate::coordinate_system::Radar`], each row is
+/// drawn as one closed polygon.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct RadarSeries {
+    /// One value per indicator, in the order of the indicators.
+    #[builder(field)]
+    pub data: Vec<Vec<f64>>,
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub name: Option<String>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub stroke: Option<Stroke>,
+    /// Color of all rows, by default every row gets the next series color.
+    #[builder(setters(option_fn(vis = "")))]
+    pub color: Option<Brush>,
+    /// Fills the polygons with their color.
+    #[builder(setters(option_fn(vis = "")))]
+    pub area_show: Option<bool>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub area_opacity: Option<f32>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub symbol_show: Option<bool>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub symbol: Option<Symbol>,
+    #[builder(setters(option_fn(vis = "")))]
+    pub symbol_size: Option<f64>,
+}
+
+impl<S: radar_series_builder::State> RadarSeriesBuilder<S> {
+    pub fn add_row(mut self, row: impl Into<Vec<f64>>) -> Self {
+        self.data.push(row.into());
+        self
+    }
+
+    pub fn set_data(mut self, data: impl IntoIterator<Item: Into<Vec<f64>>>) -> Self {
+        self.data = data.into_iter().map(Into::into).collect();
+        self
+    }
+}
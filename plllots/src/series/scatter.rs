@@ -4,14 +4,18 @@ use peniko::Brush;
 
 use crate::{
     chart::{ChartHelper, Theme},
-    component::SingleCartesianAxis,
+    component::{DataLabel, MarkLine, MarkPoint, SingleCartesianAxis},
+    element::Symbol,
     primitives::Primitives,
+    series::cartesian_coord,
 };
 
-use super::data::PlotData;
+use super::data::{Dataset, PlotData};
 
 #[derive(Debug, Builder, Clone, PartialEq)]
 pub struct Scatter {
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub name: Option<String>,
     #[builder(setters(option_fn(vis = "")))]
     pub stroke: Option<Stroke>,
     #[builder(setters(option_fn(vis = "")))]
@@ -19,11 +23,25 @@ pub struct Scatter {
     #[builder(setters(option_fn(vis = "")))]
     pub fill_color: Option<Brush>,
     #[builder(setters(option_fn(vis = "")))]
+    pub symbol: Option<Symbol>,
+    /// Rotation of the symbols in radians, clockwise.
+    #[builder(setters(option_fn(vis = "")))]
+    pub symbol_rotation: Option<f64>,
+    #[builder(setters(option_fn(vis = "")))]
     pub symbol_size: Option<f64>,
     #[builder(default = 0, setters(option_fn(vis = "")))]
     pub x_axis_index: usize,
     #[builder(default = 0, setters(option_fn(vis = "")))]
     pub y_axis_index: usize,
+    /// Clips the series to the plot area.
+    #[builder(default = true, setters(option_fn(vis = "")))]
+    pub clip: bool,
+    #[builder(setters(option_fn(vis = "")))]
+    pub label: Option<DataLabel>,
+    #[builder(default)]
+    pub mark_lines: Vec<MarkLine>,
+    #[builder(default)]
+    pub mark_points: Vec<MarkPoint>,
     #[builder(into)]
     pub data: ScatterData,
 }
@@ -36,7 +54,16 @@ pub struct ScatterData {
     pub secondary_data_index: usize,
     #[builder(setters(option_fn(vis = "")))]
     pub symbol_size_index: Option<usize>,
-    #[builder(setters(vis = ""))]
+    /// Dimension of the dataset of the chart read instead of the secondary data.
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub x: Option<String>,
+    /// Dimension of the dataset of the chart read instead of the primary data.
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub y: Option<String>,
+    /// Dimension of the dataset of the chart read instead of the symbol size data.
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub size: Option<String>,
+    #[builder(setters(vis = ""), default)]
     pub plot_data: Vec<PlotData>,
 }
 
@@ -72,6 +99,37 @@ impl<S: scatter_data_builder::State> ScatterDataBuilder<S> {
     }
 }
 
+impl ScatterData {
+    /// The primary or secondary data, looked up in `dataset` if it names a dimension.
+    pub(crate) fn values<'a>(
+        &'a self,
+        primary: bool,
+        dataset: Option<&'a Dataset>,
+    ) -> Option<&'a PlotData> {
+        match primary {
+            true => self.column(&self.y, Some(self.primary_data_index), dataset),
+            false => self.column(&self.x, Some(self.secondary_data_index), dataset),
+        }
+    }
+
+    /// The symbol sizes, if the series has any.
+    pub(crate) fn sizes<'a>(&'a self, dataset: Option<&'a Dataset>) -> Option<&'a PlotData> {
+        self.column(&self.size, self.symbol_size_index, dataset)
+    }
+
+    fn column<'a>(
+        &'a self,
+        dimension: &Option<String>,
+        index: Option<usize>,
+        dataset: Option<&'a Dataset>,
+    ) -> Option<&'a PlotData> {
+        match dimension {
+            Some(name) => dataset?.get(name),
+            None => self.plot_data.get(index?),
+        }
+    }
+}
+
 impl Scatter {
     pub(crate) fn draw_scatter<'a>(
         &'a self,
@@ -81,7 +139,10 @@ impl Scatter {
         helper: &ChartHelper,
         primitives: &mut Vec<Primitives<'a>>,
         theme: &'a Theme,
-    ) {
+    ) -> Vec<Primitives<'a>> {
+        let Some(primary) = self.data.values(true, helper.dataset) else {
+            return Vec::new();
+        };
         match (x_axis, y_axis) {
             (SingleCartesianAxis::Category(_x_axis), SingleCartesianAxis::Category(_y_axis)) => {
                 todo!()
@@ -94,16 +155,14 @@ impl Scatter {
                 let y_pos =
                     y_axis.pos_closure(&crate::component::AxisType::YAxis, &y_helper, helper);
 
-                let primary_data_index = self.data.primary_data_index;
                 self.draw(
                     primitives,
+                    helper,
                     theme,
                     series_index,
-                    &x_pos,
-                    &y_pos,
-                    primary_data_index,
-                    primary_data_index,
-                );
+                    &cartesian_coord(x_pos, y_pos),
+                    (primary, primary),
+                )
             }
             (
                 SingleCartesianAxis::Value((x_axis, x_helper)),
@@ -113,16 +172,14 @@ impl Scatter {
                     x_axis.pos_closure(&crate::component::AxisType::XAxis, &x_helper, helper);
                 let y_pos = y_axis.pos_closure(&crate::component::AxisType::YAxis, helper);
 
-                let primary_data_index = self.data.primary_data_index;
                 self.draw(
                     primitives,
+                    helper,
                     theme,
                     series_index,
-                    &x_pos,
-                    &y_pos,
-                    primary_data_index,
-                    primary_data_index,
-                );
+                    &cartesian_coord(x_pos, y_pos),
+                    (primary, primary),
+                )
             }
             (
                 SingleCartesianAxis::Value((x_axis, x_helper)),
@@ -132,59 +189,106 @@ impl Scatter {
                     x_axis.pos_closure(&crate::component::AxisType::XAxis, &x_helper, helper);
                 let y_pos =
                     y_axis.pos_closure(&crate::component::AxisType::YAxis, &y_helper, helper);
-                let primary_data_index = self.data.primary_data_index;
-                let secondary_data_index = self.data.secondary_data_index;
+                let Some(secondary) = self.data.values(false, helper.dataset) else {
+                    return Vec::new();
+                };
                 self.draw(
                     primitives,
+                    helper,
                     theme,
                     series_index,
-                    &x_pos,
-                    &y_pos,
-                    primary_data_index,
-                    secondary_data_index,
-                );
+                    &cartesian_coord(x_pos, y_pos),
+                    (primary, secondary),
+                )
             }
-        };
+        }
     }
 
-    fn draw<'a>(
+    /// Draws the series and returns its data labels, so that they can be drawn unclipped.
+    pub(crate) fn draw<'a>(
         &'a self,
         primitives: &mut Vec<Primitives<'a>>,
+        helper: &ChartHelper,
         theme: &'a Theme,
         series_index: usize,
-        x_pos: &impl Fn(Option<usize>, Option<f64>) -> f64,
-        y_pos: &impl Fn(Option<usize>, Option<f64>) -> f64,
-        primary_data_index: usize,
-        secondary_data_index: usize,
-    ) {
-        // TODO readd later
-        // let allocation_size = self.data.plot_data[primary_data_index].len();
-        // primitives.reserve(allocation_size);
-
-        for (index, (primary_value, secondary_value)) in self.data.plot_data[primary_data_index]
-            .iter()
-            .zip(self.data.plot_data[secondary_data_index].iter())
-            .enumerate()
-        {
-            let radius = match self.data.symbol_size_index {
-                Some(i) => self.data.plot_data[i][index],
-                None => self.symbol_size.unwrap_or(theme.scatter.symbol_size),
-            };
-            primitives.push(crate::primitives::Primitives::Circle(
-                crate::primitives::Circle {
-                    stroke: self.stroke.as_ref().unwrap_or(&theme.scatter.stroke),
-                    stroke_color: self
-                        .stroke_color
-                        .as_ref()
-                        .unwrap_or(&theme.scatter.stroke_color),
-                    fill_color: self
-                        .fill_color
-                        .as_ref()
-                        .unwrap_or(&theme.series_colors[series_index % theme.series_colors.len()]),
-                    coord: Point::new(x_pos(index, *secondary_value), y_pos(index, *primary_value)),
-                    radius,
-                },
-            ));
+        coord: &impl Fn(usize, Option<f64>, Option<f64>) -> Point,
+        (primary_data, secondary_data): (&PlotData, &PlotData),
+    ) -> Vec<Primitives<'a>> {
+        let stroke = self.stroke.as_ref().unwrap_or(&theme.scatter.stroke);
+        let stroke_color = self
+            .stroke_color
+            .as_ref()
+            .unwrap_or(&theme.scatter.stroke_color);
+        let fill_color = self
+            .fill_color
+            .as_ref()
+            .unwrap_or(&theme.series_colors[series_index % theme.series_colors.len()]);
+        let symbol = self.symbol.as_ref().unwrap_or(&theme.scatter.symbol);
+        let symbol_size = self.symbol_size.unwrap_or(theme.scatter.symbol_size);
+
+        let coords = (0..primary_data.len().min(secondary_data.len())).map(|index| {
+            coord(
+                index,
+                secondary_data.get_float(index),
+                primary_data.get_float(index),
+            )
+        });
+        let sizes = self.data.sizes(helper.dataset);
+        let radius = |index: usize| match sizes {
+            Some(sizes) => sizes.get_float(index).unwrap_or(symbol_size),
+            None => symbol_size,
+        };
+
+        match sizes {
+            Some(_) => {
+                primitives.reserve(primary_data.len());
+                for (index, coord) in coords.clone().enumerate() {
+                    primitives.push(match symbol {
+                        Symbol::Circle => {
+                            crate::primitives::Primitives::Circle(crate::primitives::Circle {
+                                stroke,
+                                stroke_color,
+                                fill_color,
+                                coord,
+                                radius: radius(index),
+                            })
+                        }
+                        _ => crate::primitives::Primitives::MultiSymbol(
+                            crate::primitives::MultiSymbol {
+                                stroke,
+                                stroke_color,
+                                fill_color,
+                                symbol,
+                                rotation: self.symbol_rotation,
+                                coords: vec![coord],
+                                radius: radius(index),
+                            },
+                        ),
+                    });
+                }
+            }
+            None => primitives.push(
+                crate::primitives::MultiSymbol {
+                    stroke,
+                    stroke_color,
+                    fill_color,
+                    symbol,
+                    rotation: self.symbol_rotation,
+                    coords: coords.clone().collect(),
+                    radius: symbol_size,
+                }
+                .into_primitive(),
+            ),
+        }
+
+        let mut labels = Vec::new();
+        if let Some(label) = &self.label {
+            let points = coords.enumerate().map(|(index, coord)| {
+                let value = primary_data.get_float(index).unwrap_or(f64::NAN);
+                (coord, value, radius(index))
+            });
+            label.draw(points, helper, &mut labels, theme);
         }
+        labels
     }
 }
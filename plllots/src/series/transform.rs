@@ -0,0 +1,505 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use bon::Builder;
+
+use super::data::{Dataset, Numbers, PlotData};
+
+/// A single value of a dimension, compared against by [`Condition`]s.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Float(f64),
+    String(String),
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+/// Test of a [`Transform::Filter`], which keeps the rows passing it. Comparisons with NaN fail.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    Eq(Value),
+    Ne(Value),
+    Lt(f64),
+    Le(f64),
+    Gt(f64),
+    Ge(f64),
+    /// Equal to one of the values.
+    In(Vec<Value>),
+}
+
+impl Condition {
+    fn test(&self, cell: Cell) -> bool {
+        match self {
+            Condition::Eq(expected) => cell.eq(expected),
+            Condition::Ne(expected) => !cell.eq(expected),
+            Condition::Lt(bound) => cell.float() < *bound,
+            Condition::Le(bound) => cell.float() <= *bound,
+            Condition::Gt(bound) => cell.float() > *bound,
+            Condition::Ge(bound) => cell.float() >= *bound,
+            Condition::In(values) => values.iter().any(|value| cell.eq(value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// How the values of a group are combined into one, NaN values are ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Aggregation {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    /// Number of rows in the group.
+    Count,
+}
+
+impl Aggregation {
+    fn apply(&self, values: impl Iterator<Item = f64>, rows: usize) -> f64 {
+        let values = values.filter(|v| !v.is_nan());
+        match self {
+            Aggregation::Sum => values.sum(),
+            Aggregation::Mean => {
+                let (sum, count) = values.fold((0.0, 0), |(sum, count), v| (sum + v, count + 1));
+                sum / count as f64
+            }
+            Aggregation::Min => values.reduce(f64::min).unwrap_or(f64::NAN),
+            Aggregation::Max => values.reduce(f64::max).unwrap_or(f64::NAN),
+            Aggregation::Count => rows as f64,
+        }
+    }
+}
+
+/// A dimension of the rows of a group combined into one value.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct Aggregate {
+    #[builder(into)]
+    pub dimension: String,
+    pub aggregation: Aggregation,
+    /// Name of the resulting dimension, defaults to the aggregated one.
+    #[builder(into, setters(option_fn(vis = "")))]
+    pub name: Option<String>,
+}
+
+impl<S: Into<String>> From<(S, Aggregation)> for Aggregate {
+    fn from((dimension, aggregation): (S, Aggregation)) -> Self {
+        Self {
+            dimension: dimension.into(),
+            aggregation,
+            name: None,
+        }
+    }
+}
+
+/// Computes a float for every row of a [`Transform::Derive`]. The result is a time if it
+/// floors or offsets a time, e.g. timestamps rounded down to the hour.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    Dimension(String),
+    Constant(f64),
+    Add(Box<Expression>, Box<Expression>),
+    Sub(Box<Expression>, Box<Expression>),
+    Mul(Box<Expression>, Box<Expression>),
+    Div(Box<Expression>, Box<Expression>),
+    /// Rounds down to a multiple of the width, e.g. timestamps to the hour with 3600.
+    Floor(Box<Expression>, f64),
+}
+
+impl From<&str> for Expression {
+    fn from(value: &str) -> Self {
+        Self::Dimension(value.to_string())
+    }
+}
+
+impl From<f64> for Expression {
+    fn from(value: f64) -> Self {
+        Self::Constant(value)
+    }
+}
+
+impl Expression {
+    /// The values of all rows, reading each dimension once.
+    fn evaluate(&self, table: &Table) -> Vec<f64> {
+        let binary = |a: &Expression, b: &Expression, op: fn(f64, f64) -> f64| {
+            let b = b.evaluate(table);
+            let mut a = a.evaluate(table);
+            a.iter_mut().zip(b).for_each(|(a, b)| *a = op(*a, b));
+            a
+        };
+        match self {
+            Expression::Dimension(name) => {
+                let column = table.column(name);
+                (0..table.rows)
+                    .map(|row| column.cell(row).float())
+                    .collect()
+            }
+            Expression::Constant(value) => vec![*value; table.rows],
+            Expression::Add(a, b) => binary(a, b, |a, b| a + b),
+            Expression::Sub(a, b) => binary(a, b, |a, b| a - b),
+            Expression::Mul(a, b) => binary(a, b, |a, b| a * b),
+            Expression::Div(a, b) => binary(a, b, |a, b| a / b),
+            Expression::Floor(a, width) => {
+                let mut values = a.evaluate(table);
+                values
+                    .iter_mut()
+                    .for_each(|value| *value = (*value / width).floor() * width);
+                values
+            }
+        }
+    }
+
+    fn is_time(&self, table: &Table) -> bool {
+        match self {
+            Expression::Dimension(name) => matches!(table.data(name), Some(PlotData::Time(_))),
+            Expression::Constant(_) | Expression::Mul(..) | Expression::Div(..) => false,
+            // The difference of two times is a duration.
+            Expression::Add(a, b) => a.is_time(table) != b.is_time(table),
+            Expression::Sub(a, b) => a.is_time(table) && !b.is_time(table),
+            Expression::Floor(a, _) => a.is_time(table),
+        }
+    }
+}
+
+/// A step applied to the rows of a [`Dataset`] before the chart is laid out.
+///
+/// The rows are the indices shorter than every dimension. Dimensions a transform refers to
+/// but the dataset lacks read as NaN.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Transform {
+    /// Keeps the rows whose `dimension` passes the `condition`.
+    Filter {
+        dimension: String,
+        condition: Condition,
+    },
+    /// Orders the rows by `dimension`, keeping the order of equal ones.
+    Sort { dimension: String, order: SortOrder },
+    /// One row per distinct combination of the `by` dimensions in the order they first appear,
+    /// with only those and the `aggregates` as dimensions.
+    Group {
+        by: Vec<String>,
+        aggregates: Vec<Aggregate>,
+    },
+    /// Adds the dimension `name`, or replaces it if the dataset already has one.
+    Derive {
+        name: String,
+        expression: Expression,
+    },
+}
+
+/// The dimensions of a dataset while its transforms run.
+struct Table {
+    dimensions: Vec<(String, PlotData)>,
+    rows: usize,
+}
+
+/// A dimension of a [`Table`], looked up once per transform instead of once per row.
+#[derive(Clone, Copy)]
+enum Column<'a> {
+    Numbers(&'a Numbers),
+    Strings(&'a [String]),
+    /// Dimensions the dataset lacks read as NaN.
+    Missing,
+}
+
+impl<'a> Column<'a> {
+    fn cell(self, row: usize) -> Cell<'a> {
+        match self {
+            Column::Numbers(data) => Cell::Float(data.get(row).unwrap_or(f64::NAN)),
+            Column::Strings(data) => Cell::String(&data[row]),
+            Column::Missing => Cell::Float(f64::NAN),
+        }
+    }
+}
+
+/// A [`Value`] borrowed from its [`Column`].
+#[derive(Clone, Copy)]
+enum Cell<'a> {
+    Float(f64),
+    String(&'a str),
+}
+
+impl<'a> Cell<'a> {
+    fn float(self) -> f64 {
+        match self {
+            Cell::Float(value) => value,
+            Cell::String(_) => f64::NAN,
+        }
+    }
+
+    fn eq(self, value: &Value) -> bool {
+        match (self, value) {
+            (Cell::Float(a), Value::Float(b)) => a == *b,
+            (Cell::String(a), Value::String(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    /// Identifies the cell in a group, NaN keys are not equal to themselves but still belong
+    /// to one group.
+    fn key(self) -> Key<'a> {
+        match self {
+            Cell::Float(value) => Key::Float(value.to_bits()),
+            Cell::String(value) => Key::String(value),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash)]
+enum Key<'a> {
+    Float(u64),
+    String(&'a str),
+}
+
+impl Table {
+    fn data(&self, name: &str) -> Option<&PlotData> {
+        self.dimensions
+            .iter()
+            .find(|(d, _)| d == name)
+            .map(|(_, data)| data)
+    }
+
+    fn column(&self, name: &str) -> Column<'_> {
+        match self.data(name) {
+            Some(PlotData::Float(data) | PlotData::Time(data)) => Column::Numbers(data),
+            Some(PlotData::String(data)) => Column::Strings(data),
+            None => Column::Missing,
+        }
+    }
+
+    /// Keeps the `rows` of every dimension, in their order.
+    fn select(&mut self, rows: &[usize]) {
+        for (_, data) in &mut self.dimensions {
+            *data = select(data, rows);
+        }
+        self.rows = rows.len();
+    }
+
+    fn apply(&mut self, transform: &Transform) {
+        match transform {
+            Transform::Filter {
+                dimension,
+                condition,
+            } => {
+                let column = self.column(dimension);
+                let rows: Vec<usize> = (0..self.rows)
+                    .filter(|&row| condition.test(column.cell(row)))
+                    .collect();
+                self.select(&rows);
+            }
+            Transform::Sort { dimension, order } => {
+                let column = self.column(dimension);
+                let mut rows: Vec<usize> = (0..self.rows).collect();
+                // NaN values go last in both orders.
+                rows.sort_by(|&a, &b| {
+                    let ordering = match (column.cell(a), column.cell(b)) {
+                        (Cell::Float(a), Cell::Float(b)) => match (a.is_nan(), b.is_nan()) {
+                            (false, false) => a.total_cmp(&b),
+                            (nan_a, nan_b) => return nan_a.cmp(&nan_b),
+                        },
+                        (Cell::String(a), Cell::String(b)) => a.cmp(b),
+                        _ => Ordering::Equal,
+                    };
+                    match order {
+                        SortOrder::Ascending => ordering,
+                        SortOrder::Descending => ordering.reverse(),
+                    }
+                });
+                self.select(&rows);
+            }
+            Transform::Group { by, aggregates } => self.group(by, aggregates),
+            Transform::Derive { name, expression } => {
+                let values = expression.evaluate(self);
+                let values = match expression.is_time(self) {
+                    true => PlotData::Time(values.into()),
+                    false => values.into(),
+                };
+                match self.dimensions.iter_mut().find(|(d, _)| d == name) {
+                    Some((_, data)) => *data = values,
+                    None => self.dimensions.push((name.clone(), values)),
+                }
+            }
+        }
+    }
+
+    fn group(&mut self, by: &[String], aggregates: &[Aggregate]) {
+        let columns: Vec<Column> = by.iter().map(|name| self.column(name)).collect();
+        let mut indices: HashMap<Vec<Key>, usize> = HashMap::new();
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for row in 0..self.rows {
+            let key = columns
+                .iter()
+                .map(|column| column.cell(row).key())
+                .collect();
+            let group = *indices.entry(key).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(row);
+        }
+
+        let first_rows: Vec<usize> = groups.iter().map(|rows| rows[0]).collect();
+        let mut dimensions: Vec<(String, PlotData)> = by
+            .iter()
+            .map(|name| {
+                let data = match self.data(name) {
+                    Some(data) => select(data, &first_rows),
+                    None => vec![f64::NAN; groups.len()].into(),
+                };
+                (name.clone(), data)
+            })
+            .collect();
+        for aggregate in aggregates {
+            let column = self.column(&aggregate.dimension);
+            let values: Vec<f64> = groups
+                .iter()
+                .map(|rows| {
+                    let values = rows.iter().map(|&row| column.cell(row).float());
+                    aggregate.aggregation.apply(values, rows.len())
+                })
+                .collect();
+            let name = aggregate.name.as_ref().unwrap_or(&aggregate.dimension);
+            dimensions.push((name.clone(), values.into()));
+        }
+        self.dimensions = dimensions;
+        self.rows = groups.len();
+    }
+}
+
+fn select(data: &PlotData, rows: &[usize]) -> PlotData {
+    match data {
+        PlotData::Float(data) => PlotData::Float(data.select(rows)),
+        PlotData::Time(data) => PlotData::Time(data.select(rows)),
+        PlotData::String(data) => {
+            PlotData::String(rows.iter().map(|&row| data[row].clone()).collect())
+        }
+    }
+}
+
+/// The dimensions of `dataset` after running its transforms in order.
+pub(crate) fn run(dataset: &Dataset) -> Vec<(String, PlotData)> {
+    let rows = dataset
+        .dimensions
+        .iter()
+        .map(|(_, data)| data.len())
+        .min()
+        .unwrap_or(0);
+    let all: Vec<usize> = (0..rows).collect();
+    let mut table = Table {
+        dimensions: dataset
+            .dimensions
+            .iter()
+            .map(|(name, data)| (name.clone(), select(data, &all)))
+            .collect(),
+        rows,
+    };
+    for transform in &dataset.transforms {
+        table.apply(transform);
+    }
+    table.dimensions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mean_per_hour_by_service() {
+        let dataset = Dataset::builder()
+            .add_dimension(
+                "time",
+                PlotData::Time(vec![0.0, 1800.0, 3600.0, 4000.0, 10.0].into()),
+            )
+            .add_dimension(
+                "service",
+                PlotData::String(bon::vec!["api", "api", "api", "db", "db"]),
+            )
+            .add_dimension("latency", vec![10.0, 30.0, 5.0, 7.0, f64::NAN])
+            .add_transform(Transform::Filter {
+                dimension: "service".to_string(),
+                condition: Condition::In(vec!["api".into(), "db".into()]),
+            })
+            .add_transform(Transform::Derive {
+                name: "hour".to_string(),
+                expression: Expression::Floor(Box::new("time".into()), 3600.0),
+            })
+            .add_transform(Transform::Group {
+                by: bon::vec!["service", "hour"],
+                aggregates: vec![
+                    ("latency", Aggregation::Mean).into(),
+                    Aggregate::builder()
+                        .dimension("latency")
+                        .aggregation(Aggregation::Count)
+                        .name("samples")
+                        .build(),
+                ],
+            })
+            .add_transform(Transform::Sort {
+                dimension: "latency".to_string(),
+                order: SortOrder::Descending,
+            })
+            .build()
+            .transformed()
+            .into_owned();
+
+        assert_eq!(
+            dataset.get("service"),
+            Some(&PlotData::String(bon::vec!["api", "db", "api", "db"]))
+        );
+        assert_eq!(
+            dataset.get("hour"),
+            Some(&PlotData::Time(vec![0.0, 3600.0, 3600.0, 0.0].into()))
+        );
+        // The group without any values sorts last with a NaN mean.
+        let Some(PlotData::Float(latency)) = dataset.get("latency") else {
+            panic!("aggregates are floats");
+        };
+        assert_eq!(latency.iter().take(3).collect::<Vec<_>>(), [20.0, 7.0, 5.0]);
+        assert!(latency.get(3).unwrap().is_nan());
+        assert_eq!(
+            dataset.get("samples"),
+            Some(&vec![2.0, 1.0, 1.0, 1.0].into())
+        );
+        assert_eq!(dataset.get("time"), None);
+    }
+
+    #[test]
+    fn group_by_float() {
+        let dataset = Dataset::builder()
+            .add_dimension("shard", vec![1.0, f64::NAN, 1.0, f64::NAN, 2.0])
+            .add_dimension("requests", vec![1.0, 2.0, 3.0, 4.0, 5.0])
+            .add_transform(Transform::Group {
+                by: bon::vec!["shard"],
+                aggregates: vec![("requests", Aggregation::Sum).into()],
+            })
+            .build()
+            .transformed()
+            .into_owned();
+
+        // Rows without a shard form one group.
+        let Some(PlotData::Float(shards)) = dataset.get("shard") else {
+            panic!("keys keep their type");
+        };
+        assert_eq!(shards.len(), 3);
+        assert!(shards.get(1).unwrap().is_nan());
+        assert_eq!(dataset.get("requests"), Some(&vec![4.0, 6.0, 5.0].into()));
+    }
+}
@@ -1,6 +1,39 @@
-pub fn lttb_optimized_memory(
-    x_data: &Vec<f64>,
-    y_data: &Vec<f64>,
+use crate::series::data::{Numbers, Numeric};
+
+/// A column the points are sampled from, read by index without converting it first.
+pub trait Column {
+    fn len(&self) -> usize;
+
+    fn at(&self, index: usize) -> f64;
+
+    fn to_vec(&self) -> Vec<f64> {
+        (0..self.len()).map(|index| self.at(index)).collect()
+    }
+}
+
+impl<T: Numeric> Column for [T] {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn at(&self, index: usize) -> f64 {
+        self[index].to_f64()
+    }
+}
+
+impl Column for Numbers {
+    fn len(&self) -> usize {
+        Numbers::len(self)
+    }
+
+    fn at(&self, index: usize) -> f64 {
+        self.get(index).unwrap_or(f64::NAN)
+    }
+}
+
+pub fn lttb_optimized_memory<X: Column + ?Sized, Y: Column + ?Sized>(
+    x_data: &X,
+    y_data: &Y,
     threshold: usize,
 ) -> (Vec<f64>, Vec<f64>) {
     let data_len = x_data.len();
@@ -14,8 +47,8 @@ pub fn lttb_optimized_memory(
     let every = (data_len - 2) as f64 / (threshold - 2) as f64;
     let mut a = 0;
 
-    sampled_x.push(x_data[a]);
-    sampled_y.push(y_data[a]);
+    sampled_x.push(x_data.at(a));
+    sampled_y.push(y_data.at(a));
 
     // Pre-calculate all bucket boundaries to avoid repeated calculations
     let mut bucket_starts = Vec::with_capacity(threshold);
@@ -41,7 +74,7 @@ pub fn lttb_optimized_memory(
 
         // Optimized average calculation
         let (avg_x, avg_y) = if avg_range_end - avg_range_start == 1 {
-            (x_data[avg_range_start], y_data[avg_range_start])
+            (x_data.at(avg_range_start), y_data.at(avg_range_start))
         } else {
             let mut sum_x = 0.0;
             let mut sum_y = 0.0;
@@ -52,23 +85,23 @@ pub fn lttb_optimized_memory(
             while idx + 1 < len {
                 let i1 = avg_range_start + idx;
                 let i2 = avg_range_start + idx + 1;
-                sum_x += x_data[i1] + x_data[i2];
-                sum_y += y_data[i1] + y_data[i2];
+                sum_x += x_data.at(i1) + x_data.at(i2);
+                sum_y += y_data.at(i1) + y_data.at(i2);
                 idx += 2;
             }
             // Handle remainder
             if idx < len {
                 let i = avg_range_start + idx;
-                sum_x += x_data[i];
-                sum_y += y_data[i];
+                sum_x += x_data.at(i);
+                sum_y += y_data.at(i);
             }
             (sum_x / len as f64, sum_y / len as f64)
         };
 
         let range_start = bucket_starts[i];
         let range_end = bucket_ends[i];
-        let point_a_x = x_data[a];
-        let point_a_y = y_data[a];
+        let point_a_x = x_data.at(a);
+        let point_a_y = y_data.at(a);
 
         // Optimized area calculation with early termination
         let mut max_area = -1.0;
@@ -84,10 +117,10 @@ pub fn lttb_optimized_memory(
         while idx + 1 < len {
             let i1 = range_start + idx;
             let i2 = range_start + idx + 1;
-            let p1_x = x_data[i1];
-            let p1_y = y_data[i1];
-            let p2_x = x_data[i2];
-            let p2_y = y_data[i2];
+            let p1_x = x_data.at(i1);
+            let p1_y = y_data.at(i1);
+            let p2_x = x_data.at(i2);
+            let p2_y = y_data.at(i2);
 
             let area1 = (ax_minus_avgx * (p1_y - point_a_y) - (point_a_x - p1_x) * avgy_minus_ay)
                 .abs()
@@ -110,8 +143,8 @@ pub fn lttb_optimized_memory(
         // Handle remainder
         if idx < len {
             let i = range_start + idx;
-            let p_x = x_data[i];
-            let p_y = y_data[i];
+            let p_x = x_data.at(i);
+            let p_y = y_data.at(i);
             let area =
                 (ax_minus_avgx * (p_y - point_a_y) - (point_a_x - p_x) * avgy_minus_ay).abs() * 0.5;
             if area > max_area {
@@ -120,13 +153,13 @@ pub fn lttb_optimized_memory(
         }
 
         let next_a = range_start + max_idx;
-        sampled_x.push(x_data[next_a]);
-        sampled_y.push(y_data[next_a]);
+        sampled_x.push(x_data.at(next_a));
+        sampled_y.push(y_data.at(next_a));
         a = next_a;
     }
 
-    sampled_x.push(x_data[data_len - 1]);
-    sampled_y.push(y_data[data_len - 1]);
+    sampled_x.push(x_data.at(data_len - 1));
+    sampled_y.push(y_data.at(data_len - 1));
 
     (sampled_x, sampled_y)
 }
@@ -146,7 +179,8 @@ mod tests {
             .collect();
 
         let instant = std::time::Instant::now();
-        let (result, _result2) = lttb_optimized_memory(&x_values, &y_values_sin, 1000);
+        let (result, _result2) =
+            lttb_optimized_memory(x_values.as_slice(), y_values_sin.as_slice(), 1000);
 
         println!("{:?}", instant.elapsed());
         assert_eq!(result.len(), 1000);
@@ -154,4 +188,13 @@ mod tests {
         assert_eq!(result[result.len() - 1], 999999.0);
         panic!()
     }
+
+    #[test]
+    fn numbers() {
+        let x = Numbers::from(vec![0_i64, 1, 2, 3, 4]);
+        let y = Numbers::from(vec![0.0_f32, 5.0, 0.0, 1.0, 0.0]);
+        let (x, y) = lttb_optimized_memory(&x, &y, 3);
+        assert_eq!(x, [0.0, 1.0, 4.0]);
+        assert_eq!(y, [0.0, 5.0, 0.0]);
+    }
 }
@@ -1,6 +1,8 @@
 use wide::f64x4;
 
 pub(crate) mod lttb;
+pub mod text;
+pub(crate) mod time;
 
 pub(crate) fn get_scale_details(min: f64, max: f64) -> (f64, f64, f64) {
     let epsilon = (max - min) / 1e6;
@@ -28,6 +30,18 @@ pub(crate) fn get_scale_details(min: f64, max: f64) -> (f64, f64, f64) {
     (scale_min, scale_max, step)
 }
 
+/// Values that split each interval between two consecutive major ticks into `split_number`
+/// equal parts, excluding the major ticks themselves.
+pub(crate) fn get_minor_values(major: &[f64], split_number: usize) -> Vec<f64> {
+    major
+        .windows(2)
+        .flat_map(|interval| {
+            let step = (interval[1] - interval[0]) / split_number as f64;
+            (1..split_number).map(move |i| interval[0] + step * i as f64)
+        })
+        .collect()
+}
+
 pub(crate) fn get_raw_range(data: &[f64]) -> (f64, f64) {
     if data.is_empty() {
         panic!("Cannot get range of empty slice");
@@ -105,6 +119,16 @@ mod tests {
         assert_eq!(step, 50.0);
     }
 
+    #[test]
+    fn minor_values() {
+        assert_eq!(
+            get_minor_values(&[0.0, 10.0, 20.0], 5),
+            [2.0, 4.0, 6.0, 8.0, 12.0, 14.0, 16.0, 18.0]
+        );
+        assert!(get_minor_values(&[0.0, 10.0, 20.0], 1).is_empty());
+        assert!(get_minor_values(&[0.0], 5).is_empty());
+    }
+
     #[test]
     fn basic2() {
         let data = [820.0, 932.0, 901.0, 934.0, 1290.0, 1330.0, 1320.0];
@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use kurbo::Size;
+#[cfg(feature = "bundled-fonts")]
+use parley::fontique::GenericFamily;
+use parley::{FontContext, Layout, LayoutContext, style::StyleProperty};
+
+#[cfg(feature = "bundled-fonts")]
+const BUNDLED_FONTS: [&[u8]; 1] = [include_bytes!("../../assets/fonts/DejaVuSans.ttf")];
+
+/// Measures text the same way it is laid out by the renderers, so that label and title
+/// sizes are known before the plot area is computed.
+///
+/// Clones share their fonts and the cache of measured strings, which keeps the
+/// [`CACHE_CAPACITY`] most recently used sizes. The font collection is only loaded on the
+/// first measurement.
+#[derive(Clone, Default)]
+pub struct TextMeasurer {
+    inner: Arc<Mutex<Option<TextMeasurerInner>>>,
+}
+
+/// Number of measured strings kept by a [`TextMeasurer`].
+pub const CACHE_CAPACITY: usize = 4096;
+
+struct TextMeasurerInner {
+    font_cx: FontContext,
+    layout_cx: LayoutContext,
+    cache: Cache,
+}
+
+/// Sizes by text and font size, with the tick of their last use.
+#[derive(Default)]
+struct Cache {
+    entries: HashMap<(String, u64), (Size, u64)>,
+    tick: u64,
+}
+
+impl Cache {
+    fn get(&mut self, key: &(String, u64)) -> Option<Size> {
+        self.tick += 1;
+        let (size, used) = self.entries.get_mut(key)?;
+        *used = self.tick;
+        Some(*size)
+    }
+
+    /// Inserts `size`, evicting the least recently used half of the entries when full so
+    /// that eviction is amortized over many insertions.
+    fn insert(&mut self, key: (String, u64), size: Size) {
+        if self.entries.len() >= CACHE_CAPACITY {
+            let mut ticks: Vec<u64> = self.entries.values().map(|(_, used)| *used).collect();
+            let (_, median, _) = ticks.select_nth_unstable(CACHE_CAPACITY / 2);
+            let median = *median;
+            self.entries.retain(|_, (_, used)| *used > median);
+        }
+        self.entries.insert(key, (size, self.tick));
+    }
+}
+
+impl TextMeasurerInner {
+    fn new() -> Self {
+        #[allow(unused_mut)]
+        let mut font_cx = FontContext::new();
+        #[cfg(feature = "bundled-fonts")]
+        for font in BUNDLED_FONTS {
+            let families = font_cx.collection.register_fonts(font.to_vec());
+            font_cx.collection.append_generic_families(
+                GenericFamily::SansSerif,
+                families.into_iter().map(|(family, _)| family),
+            );
+        }
+        Self {
+            font_cx,
+            layout_cx: LayoutContext::new(),
+            cache: Cache::default(),
+        }
+    }
+
+    fn layout(&mut self, text: &str, font_size: f64) -> Layout<[u8; 4]> {
+        let mut builder = self.layout_cx.ranged_builder(&mut self.font_cx, text, 1.0);
+        builder.push_default(StyleProperty::FontSize(font_size as f32));
+        let mut layout = builder.build(text);
+        layout.break_all_lines(None);
+        layout
+    }
+
+    fn measure(&mut self, text: &str, font_size: f64) -> Size {
+        let layout = self.layout(text, font_size);
+        Size::new(layout.width() as f64, layout.height() as f64)
+    }
+}
+
+impl TextMeasurer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_inner<R>(&self, f: impl FnOnce(&mut TextMeasurerInner) -> R) -> R {
+        let mut inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        f(inner.get_or_insert_with(TextMeasurerInner::new))
+    }
+
+    /// Width and height of `text` rendered on a single line.
+    pub fn measure(&self, text: &str, font_size: f64) -> Size {
+        self.with_inner(|inner| {
+            let key = (text.to_string(), font_size.to_bits());
+            if let Some(size) = inner.cache.get(&key) {
+                return size;
+            }
+            let size = inner.measure(text, font_size);
+            inner.cache.insert(key, size);
+            size
+        })
+    }
+
+    /// Like [`Self::measure`], without caching strings that are only measured once.
+    fn measure_uncached(&self, text: &str, font_size: f64) -> Size {
+        self.with_inner(|inner| inner.measure(text, font_size))
+    }
+
+    /// Size of the bounding box of `text` rotated by `rotation` radians.
+    pub fn measure_rotated(&self, text: &str, font_size: f64, rotation: Option<f64>) -> Size {
+        let size = self.measure(text, font_size);
+        match rotation {
+            Some(r) => {
+                let (sin, cos) = (r.sin().abs(), r.cos().abs());
+                Size::new(
+                    size.width * cos + size.height * sin,
+                    size.width * sin + size.height * cos,
+                )
+            }
+            None => size,
+        }
+    }
+
+    /// Shortens `text` with a trailing ellipsis until it is at most `max_width` wide.
+    pub fn truncate(&self, text: &str, font_size: f64, max_width: f64) -> String {
+        if self.measure(text, font_size).width <= max_width {
+            return text.to_string();
+        }
+        let boundaries: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+        let (mut fits, mut too_wide) = (0, boundaries.len());
+        while too_wide - fits > 1 {
+            let mid = (fits + too_wide) / 2;
+            let candidate = format!("{}…", &text[..boundaries[mid]]);
+            match self.measure_uncached(&candidate, font_size).width <= max_width {
+                true => fits = mid,
+                false => too_wide = mid,
+            }
+        }
+        format!("{}…", &text[..boundaries[fits]])
+    }
+
+    /// A single-line layout of `text`, for renderers that draw the glyphs themselves.
+    pub(crate) fn layout(&self, text: &str, font_size: f64) -> Layout<[u8; 4]> {
+        self.with_inner(|inner| inner.layout(text, font_size))
+    }
+}
+
+impl fmt::Debug for TextMeasurer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextMeasurer").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measure() {
+        let text = TextMeasurer::new();
+        let short = text.measure("Mon", 12.0);
+        let long = text.measure("Monday", 12.0);
+        let large = text.measure("Mon", 24.0);
+
+        assert!(short.width > 0.0 && short.height > 0.0);
+        assert!(long.width > short.width);
+        assert!(large.height > short.height);
+
+        let rotated = text.measure_rotated("Monday", 12.0, Some(std::f64::consts::FRAC_PI_2));
+        assert!((rotated.width - long.height).abs() < 1e-6);
+        assert!((rotated.height - long.width).abs() < 1e-6);
+    }
+
+    #[test]
+    fn truncate() {
+        let text = TextMeasurer::new();
+        let label = "Wednesday, the third day";
+        let max_width = text.measure("Wednesday", 12.0).width;
+
+        assert_eq!(text.truncate("Mon", 12.0, max_width), "Mon");
+        let truncated = text.truncate(label, 12.0, max_width);
+        assert!(truncated.ends_with('…'));
+        assert!(label.starts_with(truncated.trim_end_matches('…')));
+        assert!(text.measure(&truncated, 12.0).width <= max_width);
+    }
+
+    #[test]
+    fn bounded_cache() {
+        let text = TextMeasurer::new();
+        text.measure("Mon", 12.0);
+        for i in 0..CACHE_CAPACITY {
+            text.measure(&i.to_string(), 12.0);
+            text.measure("Mon", 12.0);
+        }
+        text.truncate("Wednesday, the third day", 12.0, 20.0);
+
+        text.with_inner(|inner| {
+            assert!(inner.cache.entries.len() <= CACHE_CAPACITY);
+            assert!(
+                inner
+                    .cache
+                    .entries
+                    .contains_key(&("Mon".to_string(), 12f64.to_bits()))
+            );
+            assert!(
+                !inner
+                    .cache
+                    .entries
+                    .keys()
+                    .any(|(text, _)| text.ends_with('…'))
+            );
+        });
+    }
+}
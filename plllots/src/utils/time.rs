@@ -0,0 +1,117 @@
+//! Scales and labels of axes showing seconds since the Unix epoch, in UTC.
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+
+/// Steps that fall on whole seconds, minutes, hours and days, coarser steps are multiples of
+/// 365 days.
+const STEPS: [f64; 21] = [
+    1.0,
+    2.0,
+    5.0,
+    10.0,
+    15.0,
+    30.0,
+    MINUTE,
+    2.0 * MINUTE,
+    5.0 * MINUTE,
+    10.0 * MINUTE,
+    15.0 * MINUTE,
+    30.0 * MINUTE,
+    HOUR,
+    2.0 * HOUR,
+    3.0 * HOUR,
+    6.0 * HOUR,
+    12.0 * HOUR,
+    DAY,
+    2.0 * DAY,
+    7.0 * DAY,
+    30.0 * DAY,
+];
+
+/// Rounded `(min, max, step_size)` of a time axis with about six steps, unlike numbers
+/// times are not extended to zero.
+pub(crate) fn get_time_scale_details(min: f64, max: f64) -> (f64, f64, f64) {
+    let rough_step = (max - min) / 6.0;
+    let step = match STEPS.iter().find(|&&step| step >= rough_step) {
+        Some(step) => *step,
+        None => {
+            let years = rough_step / (365.0 * DAY);
+            let power = 10f64.powf(years.log10().floor());
+            let nice = [1.0, 2.0, 5.0, 10.0]
+                .into_iter()
+                .find(|n| n * power >= years)
+                .unwrap_or(10.0);
+            nice * power * 365.0 * DAY
+        }
+    };
+    (
+        (min / step).floor() * step,
+        (max / step).ceil() * step,
+        step,
+    )
+}
+
+/// `seconds` as a UTC date for steps of days, otherwise as a time of day that shows the
+/// date at midnight.
+pub(crate) fn format_time(seconds: f64, step: f64) -> String {
+    let days = (seconds / DAY).floor();
+    let time = seconds - days * DAY;
+    let (year, month, day) = civil_from_days(days as i64);
+    let date = format!("{year:04}-{month:02}-{day:02}");
+    if step >= DAY || time == 0.0 {
+        return date;
+    }
+    let (hours, minutes, secs) = (
+        (time / HOUR) as u32,
+        (time % HOUR / MINUTE) as u32,
+        time % MINUTE,
+    );
+    match step >= MINUTE {
+        true => format!("{hours:02}:{minutes:02}"),
+        false => format!("{hours:02}:{minutes:02}:{:02}", secs as u32),
+    }
+}
+
+/// Year, month and day of the proleptic Gregorian calendar `days` after 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale() {
+        // Two days of hourly samples.
+        let start = 1_700_000_000.0;
+        let (min, max, step) = get_time_scale_details(start, start + 2.0 * DAY);
+        assert_eq!(step, 12.0 * HOUR);
+        assert!(min <= start && max >= start + 2.0 * DAY);
+        assert_eq!(min % step, 0.0);
+
+        // Ticks at midnight show the date.
+        assert_eq!(format_time(min, step), "12:00");
+        assert_eq!(format_time(min + step, step), "2023-11-15");
+        assert_eq!(format_time(min + 90.0, 30.0), "12:01:30");
+        assert_eq!(format_time(min + 95.0, 5.0), "12:01:35");
+        assert_eq!(format_time(-DAY, DAY), "1969-12-31");
+        assert_eq!(format_time(951_782_400.0, DAY), "2000-02-29");
+    }
+}